@@ -0,0 +1,322 @@
+use alloc::{vec, vec::Vec};
+use std::future::poll_fn;
+use std::io;
+use std::pin::Pin;
+use std::task::Poll;
+
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+use zeroize::Zeroizing;
+
+use crate::combine::CombineError;
+use crate::header::HEADER_LEN;
+use crate::polynomial::Polynomial;
+use crate::split::evaluate_into;
+use crate::stream::{chunk_len, start_combine, start_split, StreamError, CHUNK_LEN};
+
+/// Splits a secret read from `reader` into one share per writer, writing to all writers concurrently.
+///
+/// The shares use the same format as [`split_stream`](crate::split_stream), so either combine
+/// function reconstructs them. The next chunk of the secret is only read once every writer has
+/// accepted the current one, so memory stays bounded and the slowest writer sets the pace,
+/// while the others do not wait for it within a chunk.
+///
+/// The coefficients are drawn from a ChaCha20 generator seeded from the operating system,
+/// so the returned future can be sent between threads.
+///
+/// ## Arguments
+/// * `reader` - The source of the secret, read until its end.
+/// * `writers` - The sinks of the shares, one per share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Errors
+/// * The same as [`split_stream`](crate::split_stream).
+pub async fn split_async<I: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+    reader: I,
+    writers: &mut [W],
+    threshold: usize,
+) -> Result<(), StreamError> {
+    split_async_with_rng(reader, writers, threshold, &mut ChaCha20Rng::from_entropy()).await
+}
+
+/// Splits a secret read from `reader` into one share per writer, writing to all writers concurrently
+/// and drawing all randomness, including the split identifier, from `rng`.
+///
+/// ## Arguments
+/// * `reader` - The source of the secret, read until its end.
+/// * `writers` - The sinks of the shares, one per share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Errors
+/// * The same as [`split_stream`](crate::split_stream).
+pub async fn split_async_with_rng<I: AsyncRead + Unpin, W: AsyncWrite + Unpin, R: RngCore + CryptoRng + ?Sized>(
+    mut reader: I,
+    writers: &mut [W],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<(), StreamError> {
+    // Read the first chunk before writing anything, so that an empty secret is refused.
+    let mut chunk = Zeroizing::new(vec![0u8; CHUNK_LEN]);
+    let mut len = read_chunk(&mut reader, &mut chunk).await?;
+
+    let (x_coordinates, headers) = start_split(writers.len(), threshold, len == 0, rng)?;
+    write_all_each(writers, &headers).await?;
+
+    // Each frame holds the length of the chunk followed by the y-values of one share.
+    let mut frames = vec![vec![0u8; 4 + CHUNK_LEN]; writers.len()];
+    while len > 0 {
+        let mut ys: Vec<&mut [u8]> = frames.iter_mut().map(|frame| &mut frame[4..4 + len]).collect();
+        evaluate_into(&chunk[..len], &x_coordinates, threshold, rng, &mut ys);
+
+        for frame in frames.iter_mut() {
+            frame[..4].copy_from_slice(&(len as u32).to_be_bytes());
+        }
+        let filled: Vec<&[u8]> = frames.iter().map(|frame| &frame[..4 + len]).collect();
+        write_all_each(writers, &filled).await?;
+
+        len = read_chunk(&mut reader, &mut chunk).await?;
+    }
+
+    // Terminate every share with an empty chunk.
+    let terminators = vec![0u32.to_be_bytes(); writers.len()];
+    write_all_each(writers, &terminators).await?;
+    flush_each(writers).await?;
+
+    Ok(())
+}
+
+/// Combines shares written by [`split_stream`](crate::split_stream) or [`split_async`] to reconstruct
+/// the secret into `writer`, reading from all readers concurrently.
+///
+/// Since the readers are read concurrently, the headers of all shares are read before any of them
+/// is validated, and so are the length prefixes of each chunk. An error is therefore reported only
+/// once every share has reached the same point, and a share that ends early is reported as
+/// [`StreamError::Truncated`] before another share with an invalid header.
+///
+/// ## Arguments
+/// * `readers` - The sources of the shares, at least as many as the threshold.
+/// * `writer` - The sink of the secret.
+///
+/// ## Errors
+/// * The same as [`combine_stream`](crate::combine_stream).
+pub async fn combine_async<I: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+    readers: &mut [I],
+    mut writer: W,
+) -> Result<(), StreamError> {
+    if readers.len() < 2 {
+        return Err(CombineError::TooFewShares { needed: 2, found: readers.len() }.into());
+    }
+
+    let mut headers = vec![[0u8; HEADER_LEN]; readers.len()];
+    read_exact_each(readers, &mut headers).await?;
+    let reconstructor = start_combine(&headers)?;
+
+    let mut ys = vec![vec![0u8; CHUNK_LEN]; readers.len()];
+    let mut secret = Zeroizing::new(vec![0u8; CHUNK_LEN]);
+    loop {
+        let mut prefixes = vec![[0u8; 4]; readers.len()];
+        read_exact_each(readers, &mut prefixes).await?;
+        let len = chunk_len(&prefixes)?;
+        if len == 0 {
+            break;
+        }
+
+        let mut chunks: Vec<&mut [u8]> = ys.iter_mut().map(|y| &mut y[..len]).collect();
+        read_exact_each(readers, &mut chunks).await?;
+
+        Polynomial::weighted_sum(reconstructor.weights(), &chunks, &mut secret[..len]);
+        writer.write_all(&secret[..len]).await?;
+    }
+
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Reads from `reader` until `buf` is full or the reader is exhausted.
+///
+/// ## Returns
+/// * The number of bytes read, less than `buf.len()` only at the end of the reader.
+async fn read_chunk<I: AsyncRead + Unpin>(reader: &mut I, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]).await? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// Writes each of `bufs` to the writer at the same position, making progress on all writers at once.
+///
+/// Completes once every writer has accepted its whole buffer.
+async fn write_all_each<W: AsyncWrite + Unpin, B: AsRef<[u8]>>(writers: &mut [W], bufs: &[B]) -> io::Result<()> {
+    let mut written = vec![0; writers.len()];
+    poll_fn(|cx| {
+        let mut pending = false;
+        for ((writer, buf), written) in writers.iter_mut().zip(bufs).zip(written.iter_mut()) {
+            let buf = buf.as_ref();
+            while *written < buf.len() {
+                match Pin::new(&mut *writer).poll_write(cx, &buf[*written..]) {
+                    Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                    Poll::Ready(Ok(n)) => *written += n,
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => {
+                        pending = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    })
+    .await
+}
+
+/// Flushes every writer, making progress on all writers at once.
+async fn flush_each<W: AsyncWrite + Unpin>(writers: &mut [W]) -> io::Result<()> {
+    let mut flushed = vec![false; writers.len()];
+    poll_fn(|cx| {
+        let mut pending = false;
+        for (writer, flushed) in writers.iter_mut().zip(flushed.iter_mut()).filter(|(_, flushed)| !**flushed) {
+            match Pin::new(writer).poll_flush(cx) {
+                Poll::Ready(Ok(())) => *flushed = true,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => pending = true,
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    })
+    .await
+}
+
+/// Fills each of `bufs` from the reader at the same position, making progress on all readers at once.
+///
+/// ## Errors
+/// * [`StreamError::Truncated`] if a reader ends before its buffer is full.
+/// * [`StreamError::Io`] if reading fails.
+async fn read_exact_each<I: AsyncRead + Unpin, B: AsMut<[u8]>>(readers: &mut [I], bufs: &mut [B]) -> Result<(), StreamError> {
+    let mut filled = vec![0; readers.len()];
+    poll_fn(|cx| {
+        let mut pending = false;
+        for (index, ((reader, buf), filled)) in readers.iter_mut().zip(bufs.iter_mut()).zip(filled.iter_mut()).enumerate() {
+            let buf = buf.as_mut();
+            while *filled < buf.len() {
+                let mut read_buf = ReadBuf::new(&mut buf[*filled..]);
+                match Pin::new(&mut *reader).poll_read(cx, &mut read_buf) {
+                    Poll::Ready(Ok(())) if read_buf.filled().is_empty() => {
+                        return Poll::Ready(Err(StreamError::Truncated { index }))
+                    }
+                    Poll::Ready(Ok(())) => *filled += read_buf.filled().len(),
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e.into())),
+                    Poll::Pending => {
+                        pending = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    })
+    .await
+}
+
+// Test cases for the asynchronous split and combine.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combine_stream, split_stream};
+
+    // Shares written through bounded pipes are combined while they are being split.
+    #[tokio::test]
+    async fn it_splits_and_combines_through_pipes() {
+        let secret: Vec<u8> = (0..2 * CHUNK_LEN + 9).map(|i| (i % 239) as u8).collect();
+        let (writers, mut readers): (Vec<_>, Vec<_>) = (0..3).map(|_| tokio::io::duplex(1024)).unzip();
+        let mut unused = readers.pop().expect("no reader");
+
+        // The pipes hold far less than a chunk, so every side has to wait for the others.
+        let (input, mut combined, mut sink) = (&secret[..], Vec::new(), tokio::io::sink());
+        let (split, combine, drain) = tokio::join!(
+            async move {
+                // Closing the pipes lets the unused share be drained to its end.
+                let mut writers = writers;
+                split_async(input, &mut writers, 2).await
+            },
+            combine_async(&mut readers, &mut combined),
+            tokio::io::copy(&mut unused, &mut sink),
+        );
+        split.expect("split failed");
+        combine.expect("combine failed");
+        assert_eq!(drain.expect("drain failed") as usize, HEADER_LEN + secret.len() + 4 * 4);
+        assert_eq!(combined, secret);
+    }
+
+    // The asynchronous and synchronous functions share the same format.
+    #[tokio::test]
+    async fn it_matches_stream_format() {
+        let secret = b"test_secret";
+
+        let mut shares = vec![Vec::new(); 3];
+        split_async(&secret[..], &mut shares, 3).await.expect("split failed");
+        let mut readers: Vec<&[u8]> = shares.iter().map(Vec::as_slice).collect();
+        let mut combined = Vec::new();
+        combine_stream(&mut readers, &mut combined).expect("combine failed");
+        assert_eq!(combined, secret);
+
+        let mut shares = vec![Vec::new(); 3];
+        split_stream(&secret[..], &mut shares, 3).expect("split failed");
+        let mut readers: Vec<&[u8]> = shares.iter().map(Vec::as_slice).collect();
+        let mut combined = Vec::new();
+        combine_async(&mut readers, &mut combined).await.expect("combine failed");
+        assert_eq!(combined, secret);
+    }
+
+    // Shares that end early or do not line up are named.
+    #[tokio::test]
+    async fn it_fails_with_truncated_shares() {
+        let mut shares = vec![Vec::new(); 3];
+        split_async(&b"test_secret"[..], &mut shares, 2).await.expect("split failed");
+
+        let mut readers: Vec<&[u8]> = vec![&shares[0], &shares[1][..HEADER_LEN + 6], &shares[2]];
+        let error = combine_async(&mut readers, Vec::new()).await.expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Truncated { index: 1 }), "{:?}", error);
+
+        let mut readers: Vec<&[u8]> = vec![&shares[0], &shares[1][..HEADER_LEN]];
+        let error = combine_async(&mut readers, Vec::new()).await.expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Truncated { index: 1 }), "{:?}", error);
+
+        // Every header is read before any is validated, so the truncated share is reported first.
+        let in_memory = crate::split_with_header(b"test_secret", 3, 2).expect("split failed");
+        let mut readers: Vec<&[u8]> = vec![&in_memory[0], &[]];
+        let error = combine_async(&mut readers, Vec::new()).await.expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Truncated { index: 1 }), "{:?}", error);
+
+        let error = split_async(tokio::io::empty(), &mut shares, 2).await.expect_err("split succeeded");
+        assert!(matches!(error, StreamError::Split(crate::SplitError::EmptySecret)));
+    }
+
+    // The futures can be spawned on a multi-threaded runtime.
+    #[test]
+    fn it_returns_send_futures() {
+        fn assert_send<T: Send>(_: T) {}
+
+        let mut writers: Vec<Vec<u8>> = vec![Vec::new(); 2];
+        let mut readers: Vec<&[u8]> = vec![&[], &[]];
+        assert_send(split_async(&b"secret"[..], &mut writers, 2));
+        assert_send(combine_async(&mut readers, Vec::new()));
+    }
+}
@@ -1,12 +1,25 @@
+use alloc::{vec, vec::Vec};
+
+use zeroize::Zeroizing;
+
+use crate::header::HeaderError;
+use crate::ops::simd::{Backend, Multiplier};
 use crate::polynomial::Polynomial;
+use crate::reconstruct::Reconstructor;
 
-/// A type that can be used as a secret.
+/// A collection of shares that can be combined into a secret.
+///
+/// Shares may be [`Share`](crate::Share)s or raw bytes laid out as
+/// the y-values followed by the x-coordinate.
 pub trait CombineSecret {
+    /// The type of a single share.
+    type Share: AsRef<[u8]>;
+
     /// Returns the number of shares.
     fn len(&self) -> usize;
 
     /// Returns an iterator over the shares.
-    fn iter(&self) -> std::slice::Iter<Vec<u8>>;
+    fn iter(&self) -> core::slice::Iter<'_, Self::Share>;
 
     /// Returns the share at the specified index.
     /// 
@@ -14,122 +27,366 @@ pub trait CombineSecret {
     /// When possible, it is recommended to override this method
     /// with a more efficient implementation.
     #[inline]
-    fn get(&self, index: usize) -> &Vec<u8> {
+    fn get(&self, index: usize) -> &Self::Share {
         self.iter().nth(index).unwrap()
     }
 }
 
-impl<const N: usize> CombineSecret for [Vec<u8>; N] {
+impl<S: AsRef<[u8]>, const N: usize> CombineSecret for [S; N] {
+    type Share = S;
+
     #[inline]
     fn len(&self) -> usize {
         N
     }
 
     #[inline]
-    fn iter(&self) -> std::slice::Iter<Vec<u8>> {
+    fn iter(&self) -> core::slice::Iter<'_, Self::Share> {
         self[..].iter()
     }
 
     #[inline]
-    fn get(&self, index: usize) -> &Vec<u8> {
+    fn get(&self, index: usize) -> &Self::Share {
         self[..].get(index).unwrap()
     }
 }
 
 macro_rules! impl_array {
     ($($t:ty),+ $(,)?) => {$(
-        impl CombineSecret for $t {
+        impl<S: AsRef<[u8]>> CombineSecret for $t {
+            type Share = S;
+
             #[inline]
             fn len(&self) -> usize {
                 self[..].len()
             }
         
             #[inline]
-            fn iter(&self) -> std::slice::Iter<Vec<u8>> {
+            fn iter(&self) -> core::slice::Iter<'_, Self::Share> {
                 self[..].iter()
             }
         
             #[inline]
-            fn get(&self, index: usize) -> &Vec<u8> {
+            fn get(&self, index: usize) -> &Self::Share {
                 self[..].get(index).unwrap()
             }
         }
     )+};
 }
 
-impl_array!(&[Vec<u8>], [Vec<u8>]);
+impl_array!(&[S], [S]);
 
 macro_rules! impl_vec {
     ($($t:ty),+ $(,)?) => {$(
-        impl CombineSecret for $t {
+        impl<S: AsRef<[u8]>> CombineSecret for $t {
+            type Share = S;
+
             #[inline]
             fn len(&self) -> usize {
                 self.as_slice().len()
             }
 
             #[inline]
-            fn iter(&self) -> std::slice::Iter<Vec<u8>> {
+            fn iter(&self) -> core::slice::Iter<'_, Self::Share> {
                 self.as_slice().iter()
             }
 
             #[inline]
-            fn get(&self, index: usize) -> &Vec<u8> {
+            fn get(&self, index: usize) -> &Self::Share {
                 self.as_slice().get(index).unwrap()
             }
         }
     )+};
 }
 
-impl_vec!(Vec<Vec<u8>>, &Vec<Vec<u8>>);
+impl_vec!(Vec<S>, &Vec<S>);
+
+/// An error returned when shares cannot be combined into a secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CombineError {
+    /// Not enough shares were provided.
+    TooFewShares {
+        /// The minimum number of shares required.
+        needed: usize,
+        /// The number of shares provided.
+        found: usize,
+    },
+    /// A share is too short to hold at least one y-value and the x-coordinate.
+    ShareTooShort {
+        /// The position of the share in the input.
+        index: usize,
+        /// The length of the share in bytes.
+        length: usize,
+    },
+    /// A share differs in length from the first share.
+    LengthMismatch {
+        /// The position of the share in the input.
+        index: usize,
+        /// The length of the first share.
+        expected: usize,
+        /// The length of the offending share.
+        found: usize,
+    },
+    /// Two shares were evaluated at the same x-coordinate.
+    DuplicateX {
+        /// The repeated x-coordinate.
+        x: u8,
+        /// The position of the first share with this x-coordinate.
+        first: usize,
+        /// The position of the second share with this x-coordinate.
+        second: usize,
+    },
+    /// The threshold is outside of the supported range `2..=255`.
+    ThresholdOutOfRange {
+        /// The requested threshold.
+        threshold: usize,
+    },
+    /// More shares are corrupted than can be corrected.
+    Uncorrectable {
+        /// The maximum number of corrupted shares that could have been corrected.
+        correctable: usize,
+    },
+    /// Surplus shares do not lie on the polynomial reconstructed from the first `threshold` shares.
+    InconsistentShares {
+        /// The x-coordinates of the inconsistent shares.
+        xs: Vec<u8>,
+    },
+    /// The integrity digest of the reconstructed secret does not match.
+    IntegrityCheckFailed,
+    /// A share does not start with a valid header.
+    InvalidHeader {
+        /// The position of the share in the input.
+        index: usize,
+        /// The reason the header is invalid.
+        error: HeaderError,
+    },
+    /// A share belongs to a different split than the first share.
+    MixedSplits {
+        /// The position of the share in the input.
+        index: usize,
+    },
+    /// A share is not at any of the x-coordinates of a [`Reconstructor`](crate::Reconstructor).
+    UnexpectedX {
+        /// The position of the share in the input.
+        index: usize,
+        /// The x-coordinate of the share.
+        x: u8,
+    },
+    /// The output buffer does not have room for exactly one byte per y-value of the shares.
+    OutputLength {
+        /// The required length of the buffer.
+        expected: usize,
+        /// The length of the buffer.
+        found: usize,
+    },
+}
+
+impl core::fmt::Display for CombineError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CombineError::TooFewShares { needed, found } => {
+                write!(f, "need at least {} shares, have {}", needed, found)
+            }
+            CombineError::ShareTooShort { index, length } => {
+                write!(f, "share {} is {} bytes long, at least 2 are required", index, length)
+            }
+            CombineError::LengthMismatch { index, expected, found } => {
+                write!(f, "share {} is {} bytes long, expected {}", index, found, expected)
+            }
+            CombineError::DuplicateX { x, first, second } => {
+                write!(f, "shares {} and {} share the x-coordinate {}", first, second, x)
+            }
+            CombineError::ThresholdOutOfRange { threshold } => {
+                write!(f, "threshold {} is outside of the range 2..=255", threshold)
+            }
+            CombineError::Uncorrectable { correctable } => {
+                write!(f, "more than {} shares are corrupted", correctable)
+            }
+            CombineError::InconsistentShares { xs } => {
+                write!(f, "shares at x-coordinates {:?} are inconsistent with the others", xs)
+            }
+            CombineError::IntegrityCheckFailed => write!(f, "integrity check failed"),
+            CombineError::InvalidHeader { index, error } => write!(f, "share {}: {}", index, error),
+            CombineError::MixedSplits { index } => {
+                write!(f, "share {} belongs to a different split than share 0", index)
+            }
+            CombineError::UnexpectedX { index, x } => {
+                write!(f, "share {} is at the unexpected x-coordinate {}", index, x)
+            }
+            CombineError::OutputLength { expected, found } => {
+                write!(f, "output buffer is {} bytes long, expected {}", found, expected)
+            }
+        }
+    }
+}
+
+impl core::error::Error for CombineError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            CombineError::InvalidHeader { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
 
 /// Combines shares to reconstruct the secret.
 ///
 /// ## Arguments
-/// * `parts` - Shares of the secret.
+/// * `shares` - Shares of the secret, either as [`Share`](crate::Share)s or raw bytes.
 ///
 /// ## Returns
 /// * The original secret if successful; otherwise, an error.
 ///
 /// ## Errors
-/// * Returns an error if shares are inconsistent or insufficient.
-pub fn combine<T: CombineSecret>(shares: T) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+/// * [`CombineError::TooFewShares`] if fewer than two shares are provided.
+/// * [`CombineError::ShareTooShort`] if the first share holds no y-values.
+/// * [`CombineError::LengthMismatch`] if the shares are not all the same length.
+/// * [`CombineError::DuplicateX`] if two shares have the same x-coordinate.
+pub fn combine<T: CombineSecret>(shares: T) -> Result<Vec<u8>, CombineError> {
+    // Validate the shares and gather their x-coordinates.
+    let x_samples = x_samples(&shares)?;
+
+    // Compute the Lagrange weights once, then reconstruct every byte of the secret as a dot product.
+    Ok(Reconstructor::from_x_samples(x_samples).combine_validated(&shares))
+}
+
+/// Combines shares to reconstruct the secret, verifying that every surplus share agrees.
+///
+/// The secret is reconstructed from the first `threshold` shares. Each remaining share is
+/// then checked by interpolating the same polynomial at its x-coordinate and comparing
+/// the result with its y-values, so shares from another split or corrupted shares are
+/// reported instead of silently changing the result.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret, either as [`Share`](crate::Share)s or raw bytes.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`CombineError::ThresholdOutOfRange`] if `threshold` is not within `2..=255`.
+/// * [`CombineError::TooFewShares`] if fewer than `threshold` shares are provided.
+/// * [`CombineError::InconsistentShares`] if any surplus share disagrees with the others.
+/// * The same as [`combine`] for inconsistent shares.
+pub fn combine_checked<T: CombineSecret>(shares: T, threshold: usize) -> Result<Vec<u8>, CombineError> {
+    if !(2..=255).contains(&threshold) {
+        return Err(CombineError::ThresholdOutOfRange { threshold });
+    }
+
+    // Validate the shares and gather their x-coordinates.
+    let x_samples = x_samples(&shares)?;
+    if x_samples.len() < threshold {
+        return Err(CombineError::TooFewShares { needed: threshold, found: x_samples.len() });
+    }
+
+    // The first `threshold` shares determine the polynomial, the rest are checked against it.
+    let (base, surplus) = x_samples.split_at(threshold);
+
+    let first_part_len = shares.get(0).as_ref().len();
+    let mut secret = vec![0u8; first_part_len - 1];
+    let y_samples: Vec<&[u8]> = shares.iter().map(|part| &part.as_ref()[..first_part_len - 1]).collect();
+    let (base_y, surplus_y) = y_samples.split_at(threshold);
+
+    Polynomial::interpolate_batch(base, base_y, 0, &mut secret);
+
+    // Interpolate the polynomial at each surplus x-coordinate and compare.
+    let mut expected = Zeroizing::new(vec![0u8; first_part_len - 1]);
+    let mut xs = Vec::new();
+    for (&x, &y) in surplus.iter().zip(surplus_y) {
+        Polynomial::interpolate_batch(base, base_y, x, &mut expected);
+        if *expected != y {
+            xs.push(x);
+        }
+    }
+
+    if !xs.is_empty() {
+        return Err(CombineError::InconsistentShares { xs });
+    }
+
+    Ok(secret)
+}
+
+/// Combines shares to reconstruct the secret into a caller-provided buffer, without allocating.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret, either as [`Share`](crate::Share)s or raw bytes.
+/// * `out` - The buffer of the secret, exactly one byte shorter than each share.
+///
+/// ## Errors
+/// * [`CombineError::OutputLength`] if `out` is not exactly one byte shorter than the shares.
+/// * The same as [`combine`].
+pub fn combine_into<T: CombineSecret>(shares: T, out: &mut [u8]) -> Result<(), CombineError> {
+    // Validate the shares and gather their x-coordinates.
+    let mut x_samples = [0u8; 256];
+    let count = read_x_samples(&shares, &mut x_samples)?;
+    let x_samples = &x_samples[..count];
+
+    let expected = shares.get(0).as_ref().len() - 1;
+    if out.len() != expected {
+        return Err(CombineError::OutputLength { expected, found: out.len() });
+    }
+
+    // Each byte of the secret is the sum of the y-values weighted by the Lagrange basis at `x = 0`.
+    let backend = Backend::detect();
+    out.fill(0);
+    for (i, y) in shares.iter().map(AsRef::as_ref).enumerate() {
+        let weight = Multiplier::new(backend, Polynomial::basis(x_samples, i, 0));
+        weight.mul_add(out, &y[..expected]);
+    }
+
+    Ok(())
+}
+
+/// Validates shares for consistency and sufficiency, and collects their x-coordinates.
+///
+/// ## Returns
+/// * The x-coordinate of each share, in input order.
+///
+/// ## Errors
+/// * The same as [`combine`].
+pub(crate) fn x_samples<T: CombineSecret>(shares: &T) -> Result<Vec<u8>, CombineError> {
+    let mut x_samples = [0u8; 256];
+    let count = read_x_samples(shares, &mut x_samples)?;
+    Ok(x_samples[..count].to_vec())
+}
+
+/// Validates shares for consistency and sufficiency, and writes their x-coordinates to `x_samples`
+/// without allocating.
+///
+/// ## Returns
+/// * The number of shares, at most `256` since the x-coordinates are unique.
+///
+/// ## Errors
+/// * The same as [`combine`].
+fn read_x_samples<T: CombineSecret>(shares: &T, x_samples: &mut [u8; 256]) -> Result<usize, CombineError> {
     // Validate the parts for consistency and sufficiency.
-    if shares.len() < 2 || shares.get(0).len() < 2 {
-        return Err("invalid parts".into());
+    if shares.len() < 2 {
+        return Err(CombineError::TooFewShares { needed: 2, found: shares.len() });
+    }
+    if shares.get(0).as_ref().len() < 2 {
+        return Err(CombineError::ShareTooShort { index: 0, length: shares.get(0).as_ref().len() });
     }
 
     // Ensure all parts are of the same length.
-    let first_part_len = shares.get(0).len();
-    for part in shares.iter().skip(1) {
+    let first_part_len = shares.get(0).as_ref().len();
+    for (idx, part) in shares.iter().map(AsRef::as_ref).enumerate().skip(1) {
         if part.len() != first_part_len {
-            return Err("all parts must be the same length".into());
+            return Err(CombineError::LengthMismatch { index: idx, expected: first_part_len, found: part.len() });
         }
     }
 
-    // Initialize vectors to store the secret and the x and y samples.
-    let mut secret = vec![0u8; first_part_len - 1];
-    let mut x_samples = vec![0u8; shares.len()];
-    let mut y_samples = vec![0u8; shares.len()];
-
-    // Ensure that the x-coordinates are unique.
-    let mut check_set = std::collections::HashSet::new();
-    for (idx, part) in shares.iter().enumerate() {
+    // Ensure that the x-coordinates are unique, remembering where
+    // each one was first seen so a duplicate can be reported precisely.
+    let mut seen = [None; 256];
+    for (idx, part) in shares.iter().map(AsRef::as_ref).enumerate() {
         let sample = part[first_part_len - 1];
-        if check_set.contains(&sample) {
-            return Err("duplicate part detected".into());
+        if let Some(first) = seen[sample as usize] {
+            return Err(CombineError::DuplicateX { x: sample, first, second: idx });
         }
-        check_set.insert(sample);
+        seen[sample as usize] = Some(idx);
         x_samples[idx] = sample;
     }
 
-    // Reconstruct each byte of the secret using polynomial interpolation.
-    for idx in 0..(first_part_len - 1) {
-        for (i, part) in shares.iter().enumerate() {
-            y_samples[i] = part[idx];
-        }
-        let val = Polynomial::interpolate(&x_samples, &y_samples, 0);
-        secret[idx] = val;
-    }
-
-    Ok(secret)
-}
\ No newline at end of file
+    Ok(shares.len())
+}
@@ -0,0 +1,335 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::share::{Share, ShareError};
+
+/// The digits of the hexadecimal encoding.
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+/// The alphabet of the standard base64 encoding from RFC 4648.
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The alphabet of the URL-safe base64 encoding from RFC 4648.
+const BASE64URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The alphabet of Crockford's base32 encoding, which leaves out `I`, `L`, `O` and `U`.
+const BASE32_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The number of base32 characters between grouping dashes.
+const BASE32_GROUP: usize = 4;
+
+/// A text encoding for shares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Lowercase hexadecimal; decoding is case-insensitive.
+    Hex,
+    /// Standard base64 with padding, as printed by tools such as Vault; decoding also accepts missing padding.
+    Base64,
+    /// URL-safe base64 without padding.
+    Base64Url,
+    /// Crockford's base32 in dash-separated groups of four characters for dictation.
+    ///
+    /// Decoding is case-insensitive, ignores dashes and reads `I` and `L` as `1` and `O` as `0`.
+    Base32,
+}
+
+/// An error returned when text cannot be decoded into a share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A character is not part of the alphabet of the encoding.
+    InvalidCharacter {
+        /// The position of the character in the text, counted in characters.
+        position: usize,
+        /// The offending character.
+        character: char,
+    },
+    /// The number of characters cannot be produced by the encoding.
+    InvalidLength {
+        /// The number of significant characters.
+        length: usize,
+    },
+    /// The decoded bytes do not form a valid share.
+    InvalidShare(ShareError),
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter { position, character } => {
+                write!(f, "invalid character {:?} at position {}", character, position)
+            }
+            DecodeError::InvalidLength { length } => write!(f, "invalid length of {} characters", length),
+            DecodeError::InvalidShare(error) => write!(f, "invalid share: {}", error),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            DecodeError::InvalidShare(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ShareError> for DecodeError {
+    fn from(error: ShareError) -> Self {
+        DecodeError::InvalidShare(error)
+    }
+}
+
+impl Encoding {
+    /// Encodes bytes as text.
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Hex => bytes
+                .iter()
+                .flat_map(|b| [HEX_ALPHABET[(b >> 4) as usize], HEX_ALPHABET[(b & 0x0F) as usize]])
+                .map(char::from)
+                .collect(),
+            Encoding::Base64 => {
+                let mut text = encode_bits(bytes, 6, BASE64_ALPHABET);
+                // Pad to a multiple of four characters.
+                while text.len() % 4 != 0 {
+                    text.push('=');
+                }
+                text
+            }
+            Encoding::Base64Url => encode_bits(bytes, 6, BASE64URL_ALPHABET),
+            Encoding::Base32 => {
+                let digits = encode_bits(bytes, 5, BASE32_ALPHABET);
+                let groups: Vec<&str> = digits
+                    .as_bytes()
+                    .chunks(BASE32_GROUP)
+                    .map(|group| core::str::from_utf8(group).expect("alphabet is ASCII"))
+                    .collect();
+                groups.join("-")
+            }
+        }
+    }
+
+    /// Decodes text into bytes.
+    ///
+    /// ## Errors
+    /// * [`DecodeError::InvalidCharacter`] pointing at the first character outside of the alphabet.
+    /// * [`DecodeError::InvalidLength`] if the number of characters cannot be produced by the encoding.
+    pub fn decode(&self, text: &str) -> Result<Vec<u8>, DecodeError> {
+        match self {
+            Encoding::Hex => decode_bits(text.chars().enumerate(), 4, hex_value),
+            Encoding::Base64 => decode_base64(text, BASE64_ALPHABET, true),
+            Encoding::Base64Url => decode_base64(text, BASE64URL_ALPHABET, false),
+            Encoding::Base32 => decode_bits(text.chars().enumerate().filter(|&(_, c)| c != '-'), 5, base32_value),
+        }
+    }
+}
+
+impl Share {
+    /// Encodes the share, laid out as the y-values followed by the x-coordinate, as text.
+    pub fn encode(&self, encoding: Encoding) -> String {
+        encoding.encode(self.as_bytes())
+    }
+
+    /// Decodes a share, laid out as the y-values followed by the x-coordinate, from text.
+    ///
+    /// ## Errors
+    /// * [`DecodeError::InvalidCharacter`] or [`DecodeError::InvalidLength`] if the text is malformed.
+    /// * [`DecodeError::InvalidShare`] if the decoded bytes do not form a share.
+    pub fn decode(text: &str, encoding: Encoding) -> Result<Share, DecodeError> {
+        Ok(Share::try_from(encoding.decode(text)?)?)
+    }
+}
+
+// Shares are displayed in lowercase hexadecimal.
+impl core::fmt::Display for Share {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.encode(Encoding::Hex))
+    }
+}
+
+// Shares are parsed from hexadecimal, as displayed.
+impl core::str::FromStr for Share {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Share::decode(s, Encoding::Hex)
+    }
+}
+
+/// Encodes bytes as a big-endian bit stream, `width` bits per character, padding the last character with zeros.
+fn encode_bits(bytes: &[u8], width: u32, alphabet: &[u8]) -> String {
+    let mask = (1u32 << width) - 1;
+    let mut text = String::with_capacity((bytes.len() * 8).div_ceil(width as usize));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= width {
+            bits -= width;
+            text.push(char::from(alphabet[((buffer >> bits) & mask) as usize]));
+        }
+    }
+    if bits > 0 {
+        text.push(char::from(alphabet[((buffer << (width - bits)) & mask) as usize]));
+    }
+    text
+}
+
+/// Decodes characters carrying `width` bits each as a big-endian bit stream.
+///
+/// Any bits left over after the last full byte must be fewer than `width` and zero,
+/// as produced by [`encode_bits`].
+fn decode_bits(
+    chars: impl Iterator<Item = (usize, char)>,
+    width: u32,
+    value: impl Fn(char) -> Option<u8>,
+) -> Result<Vec<u8>, DecodeError> {
+    let mut bytes = Vec::new();
+    let (mut buffer, mut bits, mut length) = (0u32, 0u32, 0usize);
+    let mut last = None;
+
+    for (position, character) in chars {
+        let digit = value(character).ok_or(DecodeError::InvalidCharacter { position, character })?;
+        buffer = (buffer << width) | digit as u32;
+        bits += width;
+        length += 1;
+        last = Some((position, character));
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    if bits >= width {
+        return Err(DecodeError::InvalidLength { length });
+    }
+    if buffer & ((1 << bits) - 1) != 0 {
+        // The padding bits of the last character are not zero.
+        let (position, character) = last.expect("bits are only left over after a character");
+        return Err(DecodeError::InvalidCharacter { position, character });
+    }
+    Ok(bytes)
+}
+
+/// Decodes base64 with the given alphabet, followed by the padding `pad` allows.
+///
+/// With `pad`, the text may end in exactly as many `=` as are needed to make its length a multiple of four, or in none.
+fn decode_base64(text: &str, alphabet: &[u8; 64], pad: bool) -> Result<Vec<u8>, DecodeError> {
+    // Padding is only allowed at the end, so strip it before looking up the digits.
+    let digits = if pad { text.trim_end_matches('=') } else { text };
+    let bytes = decode_bits(digits.chars().enumerate(), 6, |c| {
+        alphabet.iter().position(|&a| char::from(a) == c).map(|v| v as u8)
+    })?;
+
+    let length = digits.chars().count();
+    let padding = text.len() - digits.len();
+    let needed = (4 - length % 4) % 4;
+    if padding > needed {
+        // Point at the first `=` beyond the padding the digits need.
+        return Err(DecodeError::InvalidCharacter { position: length + needed, character: '=' });
+    }
+    if padding != 0 && padding != needed {
+        return Err(DecodeError::InvalidLength { length: length + padding });
+    }
+    Ok(bytes)
+}
+
+/// Returns the value of a hexadecimal digit.
+fn hex_value(c: char) -> Option<u8> {
+    c.to_digit(16).map(|v| v as u8)
+}
+
+/// Returns the value of a Crockford base32 digit, accepting lowercase and the common misreadings.
+fn base32_value(c: char) -> Option<u8> {
+    let c = match c.to_ascii_uppercase() {
+        'I' | 'L' => '1',
+        'O' => '0',
+        c => c,
+    };
+    BASE32_ALPHABET.iter().position(|&a| char::from(a) == c).map(|v| v as u8)
+}
+
+// Test cases for the text encodings.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+
+    // Known encodings of the same bytes.
+    #[test]
+    fn it_encodes() {
+        let bytes = [0x00, 0x1F, 0x80, 0xFF, 0x2A];
+        assert_eq!(Encoding::Hex.encode(&bytes), "001f80ff2a");
+        assert_eq!(Encoding::Base64.encode(&bytes), "AB+A/yo=");
+        assert_eq!(Encoding::Base64Url.encode(&bytes), "AB-A_yo");
+        assert_eq!(Encoding::Base32.encode(&bytes), "00FR-1ZSA");
+        assert_eq!(Encoding::Base32.encode(b"f"), "CR");
+    }
+
+    // Every encoding decodes what it encodes, for all lengths around a full block.
+    #[test]
+    fn it_round_trips() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        for encoding in [Encoding::Hex, Encoding::Base64, Encoding::Base64Url, Encoding::Base32] {
+            for len in 0..=16 {
+                let text = encoding.encode(&bytes[100..100 + len]);
+                assert_eq!(encoding.decode(&text), Ok(bytes[100..100 + len].to_vec()));
+            }
+        }
+    }
+
+    // Decoding is lenient where the encodings allow it.
+    #[test]
+    fn it_decodes_leniently() {
+        assert_eq!(Encoding::Hex.decode("001F80FF2A"), Ok(vec![0x00, 0x1F, 0x80, 0xFF, 0x2A]));
+        assert_eq!(Encoding::Base64.decode("AB+A/yo"), Ok(vec![0x00, 0x1F, 0x80, 0xFF, 0x2A]));
+        assert_eq!(Encoding::Base64.decode("AA=="), Ok(vec![0x00]));
+        assert_eq!(Encoding::Base32.decode("oofr1zsa"), Ok(vec![0x00, 0x1F, 0x80, 0xFF, 0x2A]));
+        assert_eq!(Encoding::Base32.decode("0-0-F-R-l-Z-S-A"), Ok(vec![0x00, 0x1F, 0x80, 0xFF, 0x2A]));
+    }
+
+    // Decoding errors point at the offending character.
+    #[test]
+    fn it_fails_to_decode() {
+        assert_eq!(Encoding::Hex.decode("00zz"), Err(DecodeError::InvalidCharacter { position: 2, character: 'z' }));
+        assert_eq!(Encoding::Hex.decode("abc"), Err(DecodeError::InvalidLength { length: 3 }));
+        assert_eq!(
+            Encoding::Base64Url.decode("AB+A"),
+            Err(DecodeError::InvalidCharacter { position: 2, character: '+' })
+        );
+        assert_eq!(Encoding::Base64Url.decode("ABCDE"), Err(DecodeError::InvalidLength { length: 5 }));
+        // Padding beyond what the length requires, incomplete padding, and padding in base64url.
+        assert_eq!(
+            Encoding::Base64.decode("AAE======"),
+            Err(DecodeError::InvalidCharacter { position: 4, character: '=' })
+        );
+        assert_eq!(
+            Encoding::Base64.decode("AB+A/yo=="),
+            Err(DecodeError::InvalidCharacter { position: 8, character: '=' })
+        );
+        assert_eq!(Encoding::Base64.decode("AA="), Err(DecodeError::InvalidLength { length: 3 }));
+        assert_eq!(
+            Encoding::Base64Url.decode("AB-A_yo="),
+            Err(DecodeError::InvalidCharacter { position: 7, character: '=' })
+        );
+        assert_eq!(
+            Encoding::Base32.decode("00FR-1ZUA"),
+            Err(DecodeError::InvalidCharacter { position: 7, character: 'U' })
+        );
+        // Non-zero padding bits in the last character.
+        assert_eq!(Encoding::Base32.decode("CS"), Err(DecodeError::InvalidCharacter { position: 1, character: 'S' }));
+    }
+
+    // Shares are displayed and parsed as hexadecimal.
+    #[test]
+    fn it_displays_and_parses_shares() {
+        let share = Share::new(0x2A, &[0xDE, 0xAD]).expect("invalid share");
+        assert_eq!(share.to_string(), "dead2a");
+        assert_eq!("DEAD2A".parse::<Share>(), Ok(share.clone()));
+
+        let text = share.encode(Encoding::Base32);
+        assert_eq!(Share::decode(&text, Encoding::Base32), Ok(share));
+
+        assert_eq!("dead00".parse::<Share>(), Err(DecodeError::InvalidShare(ShareError::ZeroX)));
+    }
+}
@@ -0,0 +1,430 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+
+use crate::combine::{combine_checked, CombineError, CombineSecret};
+use crate::share::{Share, ShareError};
+use crate::split::{split_with_rng, Secret, SplitError};
+
+/// The magic bytes at the start of every share with a header.
+const MAGIC: [u8; 4] = *b"SHMR";
+
+/// The current version of the header format.
+pub const HEADER_VERSION: u8 = 1;
+
+/// The identifier of `GF(2^8)` with the irreducible polynomial `x^8 + x^4 + x^3 + x + 1`.
+const SCHEME_GF256: u8 = 1;
+
+/// The flag marking a share written by [`split_stream`](crate::split_stream), whose y-values follow in chunks.
+pub(crate) const FLAG_STREAM: u8 = 0x01;
+
+/// The length of the random split identifier.
+pub const SPLIT_ID_LEN: usize = 16;
+
+/// The length of the header in bytes.
+pub const HEADER_LEN: usize = 26;
+
+/// The self-describing header of a share.
+///
+/// A share with a header is laid out as follows, followed by the y-values:
+///
+/// | Offset | Length | Field                                                |
+/// |--------|--------|------------------------------------------------------|
+/// | 0      | 4      | Magic bytes `SHMR`                                   |
+/// | 4      | 1      | Format version, currently `1`                        |
+/// | 5      | 1      | Field and polynomial, `1` for `GF(2^8)` over `0x11B` |
+/// | 6      | 1      | Flags, bit `0` marks a streamed share                |
+/// | 7      | 1      | Threshold                                            |
+/// | 8      | 1      | Total number of parts                                |
+/// | 9      | 16     | Random split identifier                              |
+/// | 25     | 1      | x-coordinate                                         |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShareHeader {
+    /// The format version.
+    version: u8,
+    /// The field and polynomial identifier.
+    scheme: u8,
+    /// The flags, with all but [`FLAG_STREAM`] reserved for future versions.
+    flags: u8,
+    /// The minimum number of shares required to reconstruct the secret.
+    threshold: u8,
+    /// The total number of shares created.
+    parts: u8,
+    /// The random identifier shared by all shares of the same split.
+    split_id: [u8; SPLIT_ID_LEN],
+    /// The x-coordinate of the share.
+    x: u8,
+}
+
+/// An error returned when a share header cannot be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderError {
+    /// The bytes are too short to hold the header and at least one y-value.
+    TooShort {
+        /// The length of the bytes.
+        length: usize,
+    },
+    /// The bytes do not start with the magic bytes.
+    BadMagic,
+    /// The format version is not supported.
+    UnsupportedVersion {
+        /// The format version.
+        version: u8,
+    },
+    /// The field and polynomial identifier is not supported.
+    UnsupportedScheme {
+        /// The field and polynomial identifier.
+        scheme: u8,
+    },
+    /// Reserved flags are set.
+    UnsupportedFlags {
+        /// The flags.
+        flags: u8,
+    },
+    /// The threshold is below two or above the number of parts.
+    InvalidThreshold {
+        /// The threshold.
+        threshold: u8,
+        /// The total number of parts.
+        parts: u8,
+    },
+    /// The x-coordinate is zero.
+    ZeroX,
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HeaderError::TooShort { length } => {
+                write!(f, "share is {} bytes long, at least {} are required", length, HEADER_LEN + 1)
+            }
+            HeaderError::BadMagic => write!(f, "share does not start with a header"),
+            HeaderError::UnsupportedVersion { version } => write!(f, "unsupported header version {}", version),
+            HeaderError::UnsupportedScheme { scheme } => write!(f, "unsupported field identifier {}", scheme),
+            HeaderError::UnsupportedFlags { flags } => write!(f, "unsupported header flags {:#04x}", flags),
+            HeaderError::InvalidThreshold { threshold, parts } => {
+                write!(f, "invalid threshold {} for {} parts", threshold, parts)
+            }
+            HeaderError::ZeroX => write!(f, "share x-coordinate must not be zero"),
+        }
+    }
+}
+
+impl core::error::Error for HeaderError {}
+
+impl ShareHeader {
+    /// Creates a header for the current version of the format.
+    ///
+    /// The caller must ensure that the parameters are valid.
+    pub(crate) fn new(flags: u8, threshold: u8, parts: u8, split_id: [u8; SPLIT_ID_LEN], x: u8) -> ShareHeader {
+        ShareHeader { version: HEADER_VERSION, scheme: SCHEME_GF256, flags, threshold, parts, split_id, x }
+    }
+
+    /// Returns the format version.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the minimum number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold as usize
+    }
+
+    /// Returns the total number of shares created.
+    pub fn parts(&self) -> usize {
+        self.parts as usize
+    }
+
+    /// Returns the random identifier shared by all shares of the same split.
+    pub fn split_id(&self) -> &[u8; SPLIT_ID_LEN] {
+        &self.split_id
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> u8 {
+        self.x
+    }
+
+    /// Returns `true` if the share was written by [`split_stream`](crate::split_stream).
+    pub fn is_streamed(&self) -> bool {
+        self.flags & FLAG_STREAM != 0
+    }
+
+    /// Returns `true` if both headers describe shares of the same split.
+    pub(crate) fn same_split(&self, other: &ShareHeader) -> bool {
+        (self.version, self.scheme, self.flags, self.threshold, self.parts, self.split_id)
+            == (other.version, other.scheme, other.flags, other.threshold, other.parts, other.split_id)
+    }
+
+    /// Encodes the header.
+    pub fn to_bytes(&self) -> [u8; HEADER_LEN] {
+        let mut bytes = [0u8; HEADER_LEN];
+        bytes[..4].copy_from_slice(&MAGIC);
+        bytes[4] = self.version;
+        bytes[5] = self.scheme;
+        bytes[6] = self.flags;
+        bytes[7] = self.threshold;
+        bytes[8] = self.parts;
+        bytes[9..25].copy_from_slice(&self.split_id);
+        bytes[25] = self.x;
+        bytes
+    }
+
+    /// Decodes the header at the start of `bytes`, ignoring whatever follows it.
+    ///
+    /// ## Errors
+    /// * [`HeaderError::TooShort`] if `bytes` is shorter than the header.
+    /// * [`HeaderError::BadMagic`], [`HeaderError::UnsupportedVersion`], [`HeaderError::UnsupportedScheme`]
+    ///   or [`HeaderError::UnsupportedFlags`] if the header was not written by this version of the format.
+    /// * [`HeaderError::InvalidThreshold`] or [`HeaderError::ZeroX`] if the parameters are invalid.
+    pub fn parse(bytes: &[u8]) -> Result<ShareHeader, HeaderError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(HeaderError::TooShort { length: bytes.len() });
+        }
+        if bytes[..4] != MAGIC {
+            return Err(HeaderError::BadMagic);
+        }
+
+        let mut split_id = [0u8; SPLIT_ID_LEN];
+        split_id.copy_from_slice(&bytes[9..25]);
+        let header = ShareHeader {
+            version: bytes[4],
+            scheme: bytes[5],
+            flags: bytes[6],
+            threshold: bytes[7],
+            parts: bytes[8],
+            split_id,
+            x: bytes[25],
+        };
+
+        if header.version != HEADER_VERSION {
+            return Err(HeaderError::UnsupportedVersion { version: header.version });
+        }
+        if header.scheme != SCHEME_GF256 {
+            return Err(HeaderError::UnsupportedScheme { scheme: header.scheme });
+        }
+        if header.flags & !FLAG_STREAM != 0 {
+            return Err(HeaderError::UnsupportedFlags { flags: header.flags });
+        }
+        if header.threshold < 2 || header.threshold > header.parts {
+            return Err(HeaderError::InvalidThreshold { threshold: header.threshold, parts: header.parts });
+        }
+        if header.x == 0 {
+            return Err(HeaderError::ZeroX);
+        }
+        Ok(header)
+    }
+
+    /// Decodes a share with a header into the header and the share.
+    ///
+    /// ## Errors
+    /// * [`HeaderError::TooShort`] if `bytes` does not hold at least one y-value after the header.
+    /// * [`HeaderError::UnsupportedFlags`] if the share was written by [`split_stream`](crate::split_stream).
+    /// * The same as [`ShareHeader::parse`].
+    pub fn decode(bytes: &[u8]) -> Result<(ShareHeader, Share), HeaderError> {
+        let header = ShareHeader::parse(bytes)?;
+        if header.is_streamed() {
+            return Err(HeaderError::UnsupportedFlags { flags: header.flags });
+        }
+        let share = Share::new(header.x, &bytes[HEADER_LEN..]).map_err(|e| match e {
+            ShareError::TooShort { .. } => HeaderError::TooShort { length: bytes.len() },
+            ShareError::ZeroX => HeaderError::ZeroX,
+        })?;
+        Ok((header, share))
+    }
+
+    /// Encodes a share, prefixed with this header.
+    ///
+    /// ## Panics
+    /// * If the x-coordinate of the share differs from the one in the header.
+    pub fn encode(&self, share: &Share) -> Vec<u8> {
+        assert_eq!(self.x, share.x(), "x-coordinate of the share does not match the header");
+        let mut bytes = Vec::with_capacity(HEADER_LEN + share.y_values().len());
+        bytes.extend_from_slice(&self.to_bytes());
+        bytes.extend_from_slice(share.y_values());
+        bytes
+    }
+}
+
+/// Splits a secret into shares that each start with a self-describing [`ShareHeader`].
+///
+/// All shares of the split carry the same random split identifier, the threshold
+/// and the total number of parts, so that [`combine_with_header`] can refuse shares
+/// of different splits and report how many shares are missing.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of encoded shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+#[cfg(feature = "std")]
+pub fn split_with_header<T: Secret>(secret: T, parts: usize, threshold: usize) -> Result<Vec<Vec<u8>>, SplitError> {
+    split_with_header_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into shares that each start with a self-describing [`ShareHeader`],
+/// drawing all randomness, including the split identifier, from `rng`.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of encoded shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+pub fn split_with_header_rng<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+    secret: T,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Vec<u8>>, SplitError> {
+    let shares = split_with_rng(secret, parts, threshold, rng)?;
+
+    let mut split_id = [0u8; SPLIT_ID_LEN];
+    rng.fill_bytes(&mut split_id);
+
+    // The parameters have been validated by `split_with_rng`, so they fit in a byte.
+    let header = ShareHeader::new(0, threshold as u8, parts as u8, split_id, 0);
+
+    Ok(shares
+        .iter()
+        .map(|share| {
+            let mut header = header;
+            header.x = share.x();
+            header.encode(share)
+        })
+        .collect())
+}
+
+/// Combines shares created by [`split_with_header`] to reconstruct the secret.
+///
+/// The headers must all describe the same split. Any shares beyond the threshold
+/// are checked for consistency as with [`combine_checked`](crate::combine_checked).
+///
+/// ## Arguments
+/// * `shares` - Encoded shares of the secret.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`CombineError::InvalidHeader`] if a share does not start with a valid header.
+/// * [`CombineError::MixedSplits`] if a share belongs to a different split than the first one.
+/// * [`CombineError::TooFewShares`] if fewer shares than the threshold in the headers are provided.
+/// * The same as [`combine_checked`](crate::combine_checked) for inconsistent shares.
+pub fn combine_with_header<T: CombineSecret>(shares: T) -> Result<Vec<u8>, CombineError> {
+    let mut decoded: Vec<Share> = Vec::with_capacity(shares.len());
+    let mut first: Option<ShareHeader> = None;
+
+    for (index, bytes) in shares.iter().map(AsRef::as_ref).enumerate() {
+        let (header, share) = ShareHeader::decode(bytes).map_err(|error| CombineError::InvalidHeader { index, error })?;
+        match first {
+            None => first = Some(header),
+            Some(first) if !first.same_split(&header) => return Err(CombineError::MixedSplits { index }),
+            Some(_) => {}
+        }
+        decoded.push(share);
+    }
+
+    let threshold = first.map_or(2, |header| header.threshold());
+    if decoded.len() < threshold {
+        return Err(CombineError::TooFewShares { needed: threshold, found: decoded.len() });
+    }
+    combine_checked(decoded, threshold)
+}
+
+// Test cases for the share header.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // Shares with a header are reconstructed.
+    #[test]
+    fn it_combines_with_header() {
+        let mut rng = ChaCha20Rng::from_seed([1; 32]);
+        let secret = b"test_secret";
+        let shares = split_with_header_rng(secret, 5, 3, &mut rng).expect("split failed");
+        assert_eq!(shares[0].len(), HEADER_LEN + secret.len());
+
+        assert_eq!(combine_with_header(&shares[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_with_header(&shares).expect("combine failed"), secret);
+    }
+
+    // The header describes the split.
+    #[test]
+    fn it_decodes_header() {
+        let mut rng = ChaCha20Rng::from_seed([2; 32]);
+        let shares = split_with_header_rng(b"test_secret", 5, 3, &mut rng).expect("split failed");
+        let (first, share) = ShareHeader::decode(&shares[0]).expect("decode failed");
+        let (second, _) = ShareHeader::decode(&shares[1]).expect("decode failed");
+
+        assert_eq!(first.version(), HEADER_VERSION);
+        assert_eq!(first.threshold(), 3);
+        assert_eq!(first.parts(), 5);
+        assert_eq!(first.split_id(), second.split_id());
+        assert_ne!(first.x(), second.x());
+        assert_eq!(first.x(), share.x());
+        assert_eq!(first.encode(&share), shares[0]);
+    }
+
+    // Headers that were not written by this version of the format are rejected.
+    #[test]
+    fn it_rejects_invalid_headers() {
+        let mut rng = ChaCha20Rng::from_seed([3; 32]);
+        let shares = split_with_header_rng(b"test_secret", 3, 2, &mut rng).expect("split failed");
+        let modified = |offset: usize, value: u8| {
+            let mut bytes = shares[0].clone();
+            bytes[offset] = value;
+            ShareHeader::decode(&bytes).map(|(header, _)| header)
+        };
+
+        assert_eq!(ShareHeader::decode(&shares[0][..HEADER_LEN]).map(|(h, _)| h), Err(HeaderError::TooShort { length: HEADER_LEN }));
+        assert_eq!(modified(0, b'X'), Err(HeaderError::BadMagic));
+        assert_eq!(modified(4, 2), Err(HeaderError::UnsupportedVersion { version: 2 }));
+        assert_eq!(modified(5, 9), Err(HeaderError::UnsupportedScheme { scheme: 9 }));
+        assert_eq!(modified(6, 0x80), Err(HeaderError::UnsupportedFlags { flags: 0x80 }));
+        assert_eq!(modified(7, 4), Err(HeaderError::InvalidThreshold { threshold: 4, parts: 3 }));
+        assert_eq!(modified(25, 0), Err(HeaderError::ZeroX));
+    }
+
+    // Shares of different splits are refused.
+    #[test]
+    fn it_fails_with_mixed_splits() {
+        let mut rng = ChaCha20Rng::from_seed([4; 32]);
+        let first = split_with_header_rng(b"test_secret", 3, 2, &mut rng).expect("split failed");
+        let second = split_with_header_rng(b"test_secret", 3, 2, &mut rng).expect("split failed");
+
+        let mixed = vec![first[0].clone(), second[1].clone()];
+        assert_eq!(combine_with_header(mixed), Err(CombineError::MixedSplits { index: 1 }));
+    }
+
+    // The threshold in the header is enforced.
+    #[test]
+    fn it_fails_with_too_few_shares() {
+        let mut rng = ChaCha20Rng::from_seed([5; 32]);
+        let shares = split_with_header_rng(b"test_secret", 5, 4, &mut rng).expect("split failed");
+
+        let error = combine_with_header(&shares[..3]).expect_err("combine succeeded");
+        assert_eq!(error, CombineError::TooFewShares { needed: 4, found: 3 });
+        assert_eq!(error.to_string(), "need at least 4 shares, have 3");
+
+        let mut raw = shares[0].clone();
+        raw[0] = 0;
+        assert_eq!(
+            combine_with_header(vec![raw, shares[1].clone()]),
+            Err(CombineError::InvalidHeader { index: 0, error: HeaderError::BadMagic })
+        );
+    }
+}
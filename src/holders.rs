@@ -0,0 +1,208 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use rand::{CryptoRng, RngCore};
+
+use crate::share::Share;
+use crate::split::{split_at_with_rng, Secret, SplitError};
+
+/// A registry of named share holders, each bound to a fixed x-coordinate.
+///
+/// Splitting through the registry evaluates every share at the x-coordinate of its holder,
+/// so a holder keeps the same share index across any number of re-splits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolderRegistry {
+    /// The holders in order of registration, with their x-coordinates.
+    holders: Vec<(String, u8)>,
+}
+
+/// An error returned when a holder cannot be added to a [`HolderRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HolderError {
+    /// A holder with the same name is already registered.
+    DuplicateName {
+        /// The name of the holder.
+        name: String,
+    },
+    /// The x-coordinate is already bound to another holder.
+    XInUse {
+        /// The requested x-coordinate.
+        x: u8,
+        /// The name of the holder bound to it.
+        holder: String,
+    },
+    /// The x-coordinate zero cannot be bound to a holder.
+    ZeroX,
+    /// All 255 non-zero x-coordinates are already bound.
+    Full,
+}
+
+impl core::fmt::Display for HolderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HolderError::DuplicateName { name } => write!(f, "holder {:?} is already registered", name),
+            HolderError::XInUse { x, holder } => {
+                write!(f, "x-coordinate {} is already bound to holder {:?}", x, holder)
+            }
+            HolderError::ZeroX => write!(f, "x-coordinate zero cannot be bound to a holder"),
+            HolderError::Full => write!(f, "all 255 x-coordinates are already bound"),
+        }
+    }
+}
+
+impl core::error::Error for HolderError {}
+
+impl HolderRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> HolderRegistry {
+        HolderRegistry::default()
+    }
+
+    /// Registers a holder at the lowest x-coordinate that is not bound yet.
+    ///
+    /// ## Returns
+    /// * The x-coordinate bound to the holder.
+    ///
+    /// ## Errors
+    /// * [`HolderError::DuplicateName`] if the name is already registered.
+    /// * [`HolderError::Full`] if every x-coordinate is already bound.
+    pub fn register(&mut self, name: impl Into<String>) -> Result<u8, HolderError> {
+        let x = (1..=255).find(|&x| self.holder(x).is_none()).ok_or(HolderError::Full)?;
+        self.register_at(name, x)?;
+        Ok(x)
+    }
+
+    /// Registers a holder at the given x-coordinate.
+    ///
+    /// ## Errors
+    /// * [`HolderError::ZeroX`] if `x` is zero.
+    /// * [`HolderError::DuplicateName`] if the name is already registered.
+    /// * [`HolderError::XInUse`] if `x` is bound to another holder.
+    pub fn register_at(&mut self, name: impl Into<String>, x: u8) -> Result<(), HolderError> {
+        let name = name.into();
+        if x == 0 {
+            return Err(HolderError::ZeroX);
+        }
+        if self.x(&name).is_some() {
+            return Err(HolderError::DuplicateName { name });
+        }
+        if let Some(holder) = self.holder(x) {
+            return Err(HolderError::XInUse { x, holder: holder.to_owned() });
+        }
+        self.holders.push((name, x));
+        Ok(())
+    }
+
+    /// Returns the x-coordinate bound to the named holder, if registered.
+    pub fn x(&self, name: &str) -> Option<u8> {
+        self.holders.iter().find(|(n, _)| n == name).map(|&(_, x)| x)
+    }
+
+    /// Returns the name of the holder bound to the x-coordinate, if any.
+    pub fn holder(&self, x: u8) -> Option<&str> {
+        self.holders.iter().find(|&&(_, h)| h == x).map(|(n, _)| n.as_str())
+    }
+
+    /// Returns the number of registered holders.
+    pub fn len(&self) -> usize {
+        self.holders.len()
+    }
+
+    /// Returns `true` if no holders are registered.
+    pub fn is_empty(&self) -> bool {
+        self.holders.is_empty()
+    }
+
+    /// Returns an iterator over the holders and their x-coordinates, in order of registration.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u8)> {
+        self.holders.iter().map(|(n, x)| (n.as_str(), *x))
+    }
+
+    /// Returns the x-coordinates of the holders, in order of registration.
+    pub fn xs(&self) -> Vec<u8> {
+        self.holders.iter().map(|&(_, x)| x).collect()
+    }
+
+    /// Splits a secret into one share per registered holder.
+    ///
+    /// ## Returns
+    /// * The holders paired with their shares, in order of registration.
+    ///
+    /// ## Errors
+    /// * The same as [`split_at`](crate::split_at).
+    #[cfg(feature = "std")]
+    pub fn split<T: Secret>(&self, secret: T, threshold: usize) -> Result<Vec<(&str, Share)>, SplitError> {
+        self.split_with_rng(secret, threshold, &mut rand::thread_rng())
+    }
+
+    /// Splits a secret into one share per registered holder, drawing the polynomial coefficients from `rng`.
+    ///
+    /// ## Errors
+    /// * The same as [`split_at`](crate::split_at).
+    pub fn split_with_rng<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        secret: T,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Result<Vec<(&str, Share)>, SplitError> {
+        let shares = split_at_with_rng(secret, &self.xs(), threshold, rng)?;
+        Ok(self.holders.iter().map(|(n, _)| n.as_str()).zip(shares).collect())
+    }
+}
+
+// Test cases for the `HolderRegistry` type.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // Holders are bound to the lowest free x-coordinate unless one is given.
+    #[test]
+    fn it_registers_holders() {
+        let mut holders = HolderRegistry::new();
+        assert_eq!(holders.register_at("bob", 2), Ok(()));
+        assert_eq!(holders.register("alice"), Ok(1));
+        assert_eq!(holders.register("carol"), Ok(3));
+
+        assert_eq!(holders.len(), 3);
+        assert_eq!(holders.x("alice"), Some(1));
+        assert_eq!(holders.holder(2), Some("bob"));
+        assert_eq!(holders.xs(), vec![2, 1, 3]);
+    }
+
+    // Names and x-coordinates cannot be bound twice.
+    #[test]
+    fn it_rejects_conflicting_holders() {
+        let mut holders = HolderRegistry::new();
+        holders.register_at("alice", 1).expect("register failed");
+
+        assert_eq!(holders.register("alice"), Err(HolderError::DuplicateName { name: "alice".into() }));
+        assert_eq!(
+            holders.register_at("bob", 1),
+            Err(HolderError::XInUse { x: 1, holder: "alice".into() })
+        );
+        assert_eq!(holders.register_at("bob", 0), Err(HolderError::ZeroX));
+
+        for i in 2..=255 {
+            holders.register(format!("holder-{}", i)).expect("register failed");
+        }
+        assert_eq!(holders.register("late"), Err(HolderError::Full));
+    }
+
+    // Every holder keeps the same x-coordinate across splits.
+    #[test]
+    fn it_splits_for_holders() {
+        let mut rng = ChaCha20Rng::from_seed([1; 32]);
+        let mut holders = HolderRegistry::new();
+        holders.register_at("alice", 1).expect("register failed");
+        holders.register_at("bob", 42).expect("register failed");
+        holders.register_at("carol", 7).expect("register failed");
+
+        for _ in 0..3 {
+            let shares = holders.split_with_rng("secret", 2, &mut rng).expect("split failed");
+            let assigned: Vec<_> = shares.iter().map(|(n, s)| (*n, s.x())).collect();
+            assert_eq!(assigned, vec![("alice", 1), ("bob", 42), ("carol", 7)]);
+        }
+    }
+}
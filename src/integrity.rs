@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+
+use hmac::{Hmac, Mac};
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::combine::{combine, CombineError, CombineSecret};
+use crate::share::Share;
+use crate::split::{split_with_rng, Secret, SplitError};
+
+/// Length of the random salt that keys the digest.
+pub(crate) const SALT_LEN: usize = 16;
+
+/// Length of the digest after truncation.
+pub(crate) const DIGEST_LEN: usize = 8;
+
+/// Splits a secret into multiple shares, appending an integrity digest to the secret.
+///
+/// In the style of the SLIP-39 digest share, the secret is authenticated with
+/// `HMAC-SHA256` keyed by a per-split random salt. The digest, truncated to 8 bytes,
+/// and the 16-byte salt are appended to the secret before it is split, so every share
+/// is 24 bytes longer than with [`split`](crate::split). Combining with
+/// [`combine_with_digest`] then detects too few shares, or shares from different splits,
+/// instead of returning random-looking bytes.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+#[cfg(feature = "std")]
+pub fn split_with_digest<T: Secret>(secret: T, parts: usize, threshold: usize) -> Result<Vec<Share>, SplitError> {
+    split_with_digest_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares, appending an integrity digest to the secret
+/// and drawing all randomness, including the salt, from `rng`.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+pub fn split_with_digest_rng<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+    secret: T,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, SplitError> {
+    // Reject an empty secret up front, since the digest would otherwise make it non-empty.
+    if secret.is_empty() {
+        return Err(SplitError::EmptySecret);
+    }
+
+    let payload = seal(secret, rng);
+    split_with_rng(&*payload, parts, threshold, rng)
+}
+
+/// Combines shares created by [`split_with_digest`] and verifies the integrity digest.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret, either as [`Share`]s or raw bytes.
+///
+/// ## Returns
+/// * The original secret, without the digest, if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`CombineError::IntegrityCheckFailed`] if the reconstructed digest does not match,
+///   typically because too few shares, or shares from different splits, were combined.
+/// * The same as [`combine`](crate::combine) for inconsistent shares.
+pub fn combine_with_digest<T: CombineSecret>(shares: T) -> Result<Vec<u8>, CombineError> {
+    open(combine(shares)?)
+}
+
+/// Appends the truncated digest of the secret and the salt keying it.
+///
+/// ## Returns
+/// * The secret followed by the digest and the salt.
+pub(crate) fn seal<T: Secret, R: RngCore + CryptoRng + ?Sized>(secret: T, rng: &mut R) -> Zeroizing<Vec<u8>> {
+    let mut payload = Zeroizing::new(Vec::with_capacity(secret.len() + DIGEST_LEN + SALT_LEN));
+    payload.extend(secret.iter());
+
+    let mut salt = [0u8; SALT_LEN];
+    rng.fill_bytes(&mut salt);
+
+    let digest = digest(&payload, &salt);
+    payload.extend_from_slice(&digest[..DIGEST_LEN]);
+    payload.extend_from_slice(&salt);
+    payload
+}
+
+/// Verifies and strips the digest and salt appended by [`seal`].
+///
+/// ## Errors
+/// * [`CombineError::IntegrityCheckFailed`] if the payload is too short or the digest does not match.
+pub(crate) fn open(mut payload: Vec<u8>) -> Result<Vec<u8>, CombineError> {
+    if payload.len() <= DIGEST_LEN + SALT_LEN {
+        payload.zeroize();
+        return Err(CombineError::IntegrityCheckFailed);
+    }
+
+    let secret_len = payload.len() - DIGEST_LEN - SALT_LEN;
+    let (secret, trailer) = payload.split_at(secret_len);
+    let (expected, salt) = trailer.split_at(DIGEST_LEN);
+
+    let digest = digest(secret, salt);
+    if !bool::from(digest[..DIGEST_LEN].ct_eq(expected)) {
+        payload.zeroize();
+        return Err(CombineError::IntegrityCheckFailed);
+    }
+
+    // Clear the trailer before truncating, so it does not linger in the spare capacity.
+    payload[secret_len..].zeroize();
+    payload.truncate(secret_len);
+    Ok(payload)
+}
+
+/// Computes `HMAC-SHA256(salt, secret)`.
+pub(crate) fn digest(secret: &[u8], salt: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(salt).expect("HMAC accepts keys of any length");
+    mac.update(secret);
+    mac.finalize().into_bytes().into()
+}
+
+// Test cases for the integrity digest.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::split_with_rng;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // Shares with a digest are reconstructed and verified.
+    #[test]
+    fn it_combines_with_digest() {
+        let mut rng = ChaCha20Rng::from_seed([1; 32]);
+        let secret = b"test_secret";
+        let shares = split_with_digest_rng(secret, 5, 3, &mut rng).expect("split failed");
+        assert_eq!(shares[0].y_values().len(), secret.len() + DIGEST_LEN + SALT_LEN);
+
+        assert_eq!(combine_with_digest(&shares[..3]).expect("combine failed"), secret);
+        assert_eq!(combine_with_digest(&shares[2..]).expect("combine failed"), secret);
+    }
+
+    // Too few shares fail the integrity check instead of returning garbage.
+    #[test]
+    fn it_fails_with_too_few_shares() {
+        let mut rng = ChaCha20Rng::from_seed([2; 32]);
+        let shares = split_with_digest_rng(b"test_secret", 5, 3, &mut rng).expect("split failed");
+        assert_eq!(combine_with_digest(&shares[..2]), Err(CombineError::IntegrityCheckFailed));
+    }
+
+    // Shares from different splits fail the integrity check.
+    #[test]
+    fn it_fails_with_mixed_splits() {
+        let mut rng = ChaCha20Rng::from_seed([3; 32]);
+        let first = split_with_digest_rng(b"test_secret", 3, 2, &mut rng).expect("split failed");
+        let second = split_with_digest_rng(b"test_secret", 3, 2, &mut rng).expect("split failed");
+
+        let other = second.iter().find(|s| s.x() != first[0].x()).expect("no share");
+        let mixed = [first[0].clone(), other.clone()];
+        assert_eq!(combine_with_digest(&mixed[..]), Err(CombineError::IntegrityCheckFailed));
+    }
+
+    // Shares without a digest are rejected.
+    #[test]
+    fn it_fails_without_digest() {
+        let mut rng = ChaCha20Rng::from_seed([4; 32]);
+        let shares = split_with_rng(b"a secret that is long enough for a digest", 3, 2, &mut rng)
+            .expect("split failed");
+        assert_eq!(combine_with_digest(&shares), Err(CombineError::IntegrityCheckFailed));
+
+        let shares = split_with_rng(b"short", 3, 2, &mut rng).expect("split failed");
+        assert_eq!(combine_with_digest(&shares), Err(CombineError::IntegrityCheckFailed));
+        assert_eq!(split_with_digest_rng("", 3, 2, &mut rng), Err(SplitError::EmptySecret));
+    }
+}
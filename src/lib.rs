@@ -1,20 +1,81 @@
 // #![forbid(unsafe_code)]
 // #![warn(clippy::all)]
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod ops;
 mod polynomial;
 mod split;
 mod combine;
+mod reconstruct;
+mod share;
+mod holders;
+mod robust;
+mod integrity;
+mod header;
+mod encoding;
+mod wordlist;
+mod mnemonic;
+mod slip39;
+mod vault;
+mod ssss;
+mod params;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "tokio")]
+mod async_io;
 
-pub use split::split;
-pub use combine::combine;
+pub use split::{split_at_with_rng, split_deterministic, split_into, split_with_rng, SplitError};
+pub use combine::{combine, combine_checked, combine_into, CombineError};
+pub use reconstruct::Reconstructor;
+pub use share::{Share, ShareError};
+pub use params::SplitParameters;
+pub use encoding::{DecodeError, Encoding};
+pub use mnemonic::{MnemonicError, MAX_MNEMONIC_SECRET_LEN};
+pub use vault::{combine_vault_keys, decode_vault_key, split_vault_keys_with_rng, VaultError};
+pub use ssss::{ssss_combine, ssss_split_with_rng, SsssError, SSSS_MAX_SECRET_LEN};
+pub use slip39::{slip39_combine, slip39_split_with_rng, Slip39Error};
+pub use holders::{HolderError, HolderRegistry};
+pub use robust::combine_robust;
+pub use integrity::{combine_with_digest, split_with_digest_rng};
+pub use header::{
+    combine_with_header, split_with_header_rng, HeaderError, ShareHeader, HEADER_LEN, HEADER_VERSION, SPLIT_ID_LEN,
+};
+#[cfg(feature = "std")]
+pub use split::{split, split_at};
+#[cfg(feature = "std")]
+pub use vault::split_vault_keys;
+#[cfg(feature = "std")]
+pub use ssss::ssss_split;
+#[cfg(feature = "std")]
+pub use slip39::slip39_split;
+#[cfg(feature = "std")]
+pub use integrity::split_with_digest;
+#[cfg(feature = "std")]
+pub use header::split_with_header;
+#[cfg(feature = "std")]
+pub use stream::{combine_stream, split_stream, split_stream_with_rng, StreamError};
+#[cfg(feature = "rayon")]
+pub use parallel::{par_combine, par_split, par_split_with_rng};
+#[cfg(feature = "tokio")]
+pub use async_io::{combine_async, split_async, split_async_with_rng};
 
 // Test cases for the `lib` module.
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::{vec, vec::Vec};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
 
-    // The 'split' function with valid inputs.
+    // The 'split_with_rng' function with valid inputs.
     #[test]
     fn it_splits_secret() {
         let secret = "test_secret";
@@ -22,24 +83,192 @@ mod tests {
         let parts = 5;
 
         // Split the secret into shares.
-        let shares = split(secret, parts, threshold).expect("split failed");
+        let shares = split_with_rng(secret, parts, threshold, &mut ChaCha20Rng::from_seed([1; 32]))
+            .expect("split failed");
         // The number of shares should match the specified number of parts.
         assert_eq!(shares.len(), parts);
 
-        // Each share should be one byte longer than the secret to store the x-coordinate.
+        // Each share should hold one y-value per byte of the secret, plus one byte for the x-coordinate.
         for share in shares.iter() {
-            assert_eq!(share.len(), secret.len() + 1);
+            assert_eq!(share.y_values().len(), secret.len());
+            assert_eq!(share.as_bytes().len(), secret.len() + 1);
+        }
+    }
+
+    // The 'split_with_rng' function draws all of its randomness from the provided generator.
+    #[test]
+    fn it_splits_with_provided_rng() {
+        // Wraps a generator and counts the bytes drawn from it.
+        struct CountingRng(ChaCha20Rng, usize);
+
+        impl rand::RngCore for CountingRng {
+            fn next_u32(&mut self) -> u32 {
+                self.1 += 4;
+                self.0.next_u32()
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.1 += 8;
+                self.0.next_u64()
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                self.1 += dest.len();
+                self.0.fill_bytes(dest)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+                self.1 += dest.len();
+                self.0.try_fill_bytes(dest)
+            }
         }
+
+        impl rand::CryptoRng for CountingRng {}
+
+        let secret = b"test_secret";
+        let mut rng = CountingRng(ChaCha20Rng::from_seed([2; 32]), 0);
+        let shares = split_with_rng(secret, 5, 3, &mut rng).expect("split failed");
+
+        // Two random coefficients per byte of the secret, plus the shuffle.
+        assert!(rng.1 > 2 * secret.len());
+        assert_eq!(combine(&shares[..3]).expect("combine failed"), secret);
+    }
+
+    // The 'split_into' and 'combine_into' functions with buffers of the wrong length.
+    #[test]
+    fn it_fails_with_invalid_buffers() {
+        let mut rng = ChaCha20Rng::from_seed([3; 32]);
+        let mut shares = vec![vec![0u8; 12], vec![0u8; 11], vec![0u8; 12]];
+        assert_eq!(
+            split_into(b"test_secret", &mut shares, 2, &mut rng),
+            Err(SplitError::ShareLength { index: 1, expected: 12, found: 11 })
+        );
+        assert_eq!(
+            split_into(b"test_secret", &mut shares, 4, &mut rng),
+            Err(SplitError::PartsBelowThreshold { parts: 3, threshold: 4 })
+        );
+
+        shares[1].push(0);
+        split_into(b"test_secret", &mut shares, 2, &mut rng).expect("split failed");
+        let mut out = [0u8; 10];
+        assert_eq!(combine_into(&shares, &mut out), Err(CombineError::OutputLength { expected: 11, found: 10 }));
+        assert_eq!(combine_into(&shares[..1], &mut out), Err(CombineError::TooFewShares { needed: 2, found: 1 }));
+
+        let mut out = [0u8; 11];
+        combine_into(&shares[1..], &mut out).expect("combine failed");
+        assert_eq!(&out, b"test_secret");
+    }
+
+    // Decodes a hex string from a test vector file.
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("invalid hex"))
+            .collect()
+    }
+
+    // The 'split_deterministic' function against the committed known-answer vectors.
+    #[test]
+    fn it_splits_deterministic_known_answers() {
+        let vectors = include_str!("../tests/vectors/split_deterministic.txt");
+
+        let records: Vec<_> = vectors.split("\n\n").filter(|r| r.contains("seed =")).collect();
+        assert_eq!(records.len(), 4);
+
+        for record in records {
+            let (mut seed, mut secret, mut parts, mut threshold, mut expected) = ([0u8; 32], vec![], 0, 0, vec![]);
+            for line in record.lines().filter(|l| !l.starts_with('#') && !l.is_empty()) {
+                let (key, value) = line.split_once(" = ").expect("invalid line");
+                match key {
+                    "seed" => seed.copy_from_slice(&from_hex(value)),
+                    "secret" => secret = from_hex(value),
+                    "parts" => parts = value.parse().expect("invalid parts"),
+                    "threshold" => threshold = value.parse().expect("invalid threshold"),
+                    "share" => expected.push(from_hex(value)),
+                    _ => panic!("unknown key {}", key),
+                }
+            }
+
+            let shares = split_deterministic(&secret, parts, threshold, &seed).expect("split failed");
+            let shares: Vec<Vec<u8>> = shares.iter().map(Share::to_bytes).collect();
+            assert_eq!(shares, expected);
+            assert_eq!(combine(&shares[parts - threshold..]).expect("combine failed"), secret);
+        }
+    }
+
+    // The 'split_deterministic' function is reproducible for a seed and differs between seeds.
+    #[test]
+    fn it_splits_deterministic() {
+        let secret = "test_secret";
+        let first = split_deterministic(secret, 5, 3, &[7; 32]).expect("split failed");
+        let second = split_deterministic(secret, 5, 3, &[7; 32]).expect("split failed");
+        let other = split_deterministic(secret, 5, 3, &[8; 32]).expect("split failed");
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    // The 'split_at_with_rng' function evaluates the shares at exactly the given x-coordinates.
+    #[test]
+    fn it_splits_at_given_x_coordinates() {
+        let secret = b"test_secret";
+        let xs = [7, 1, 255, 42];
+
+        let shares = split_at_with_rng(secret, &xs, 3, &mut ChaCha20Rng::from_seed([4; 32])).expect("split failed");
+        let assigned: Vec<u8> = shares.iter().map(Share::x).collect();
+        assert_eq!(assigned, xs);
+        assert_eq!(combine(&shares[1..]).expect("combine failed"), secret);
+    }
+
+    // The 'split_at_with_rng' function with invalid x-coordinates.
+    #[test]
+    fn it_fails_to_split_at_invalid_x_coordinates() {
+        let secret = "test_secret";
+        let mut rng = ChaCha20Rng::from_seed([5; 32]);
+
+        assert_eq!(split_at_with_rng(secret, &[1, 0, 3], 2, &mut rng), Err(SplitError::ZeroX { index: 1 }));
+        assert_eq!(
+            split_at_with_rng(secret, &[5, 6, 5], 2, &mut rng),
+            Err(SplitError::DuplicateX { x: 5, first: 0, second: 2 })
+        );
+        assert_eq!(
+            split_at_with_rng(secret, &[1, 2], 3, &mut rng),
+            Err(SplitError::PartsBelowThreshold { parts: 2, threshold: 3 })
+        );
+        assert_eq!(
+            split_at_with_rng(secret, &[1, 2], 1, &mut rng),
+            Err(SplitError::ThresholdOutOfRange { threshold: 1 })
+        );
+        assert_eq!(split_at_with_rng("", &[1, 2], 2, &mut rng), Err(SplitError::EmptySecret));
     }
 
-    // The 'split' function with invalid inputs.
+    // The 'split_with_rng' function with invalid inputs.
     #[test]
     fn it_fails_when_split_parts_less_than_thresshold() {
         let secret = "test_secret";
         let threshold = 3;
         let parts = 2; // Less than the threshold
 
-        assert!(split(secret, parts, threshold,).is_err());
+        assert_eq!(
+            split_with_rng(secret, parts, threshold, &mut ChaCha20Rng::from_seed([6; 32])),
+            Err(SplitError::PartsBelowThreshold { parts, threshold })
+        );
+    }
+
+    // The 'split_with_rng' function with out of range parameters or an empty secret.
+    #[test]
+    fn it_fails_to_split_invalid_parameters() {
+        let secret = "test_secret";
+        let mut rng = ChaCha20Rng::from_seed([7; 32]);
+
+        // Threshold below the minimum.
+        assert_eq!(split_with_rng(secret, 5, 1, &mut rng), Err(SplitError::ThresholdOutOfRange { threshold: 1 }));
+        // Threshold above the maximum.
+        assert_eq!(split_with_rng(secret, 300, 256, &mut rng), Err(SplitError::ThresholdOutOfRange { threshold: 256 }));
+        // More parts than available x-coordinates.
+        assert_eq!(split_with_rng(secret, 256, 3, &mut rng), Err(SplitError::TooManyParts { parts: 256 }));
+        // Nothing to split.
+        assert_eq!(split_with_rng("", 5, 3, &mut rng), Err(SplitError::EmptySecret));
     }
 
     // The 'combine' function with shares randomly generated from the split function.
@@ -49,7 +278,8 @@ mod tests {
         let threshold = 3;
         let parts = 5;
 
-        let shares = split(&secret, parts, threshold).expect("split failed");
+        let shares = split_with_rng(&secret, parts, threshold, &mut ChaCha20Rng::from_seed([8; 32]))
+            .expect("split failed");
         // Choose a subset of shares that meet the threshold
         let selected_shares = &shares[..threshold];
 
@@ -57,6 +287,20 @@ mod tests {
         assert_eq!(reconstructed, secret);
     }
 
+    // The 'combine' function with shares converted to and from their byte layout.
+    #[test]
+    fn it_combines_from_share_bytes() {
+        let secret = b"test_secret";
+        let shares = split_with_rng(secret, 5, 3, &mut ChaCha20Rng::from_seed([10; 32])).expect("split failed");
+
+        let bytes: Vec<Vec<u8>> = shares.iter().map(Share::to_bytes).collect();
+        assert_eq!(combine(&bytes[2..]).expect("combine failed"), secret);
+
+        let parsed: Vec<Share> = bytes.into_iter().map(|b| Share::try_from(b).expect("invalid share")).collect();
+        assert_eq!(parsed, shares);
+        assert_eq!(combine(&parsed[1..4]).expect("combine failed"), secret);
+    }
+
     // The `combine` function with known shares.
     #[test]
     fn it_combines_from_known_shares() {
@@ -73,16 +317,58 @@ mod tests {
         assert_eq!(reconstructed, secret);
     }
 
+    // The 'combine_checked' function with consistent surplus shares.
+    #[test]
+    fn it_combines_checked() {
+        let secret = b"test_secret";
+        let shares = split_with_rng(secret, 5, 3, &mut ChaCha20Rng::from_seed([11; 32])).expect("split failed");
+
+        assert_eq!(combine_checked(&shares, 3).expect("combine failed"), secret);
+        assert_eq!(combine_checked(&shares[1..4], 3).expect("combine failed"), secret);
+    }
+
+    // The 'combine_checked' function names the surplus shares that disagree.
+    #[test]
+    fn it_fails_to_combine_checked_inconsistent_shares() {
+        let secret = b"test_secret";
+        let mut rng = ChaCha20Rng::from_seed([12; 32]);
+        let shares = split_with_rng(secret, 6, 3, &mut rng).expect("split failed");
+        let mut shares: Vec<Vec<u8>> = shares.iter().map(Share::to_bytes).collect();
+
+        // Corrupt two of the surplus shares.
+        shares[3][0] ^= 0x01;
+        shares[5][7] ^= 0x80;
+        let xs = vec![shares[3][11], shares[5][11]];
+        assert_eq!(combine_checked(&shares, 3), Err(CombineError::InconsistentShares { xs }));
+
+        // A share from another split is also inconsistent.
+        let other = split_with_rng(secret, 6, 3, &mut rng).expect("split failed");
+        let mut mixed: Vec<Vec<u8>> = shares[..3].to_vec();
+        mixed.push(other.iter().map(Share::to_bytes).find(|s| !shares[..3].iter().any(|o| o[11] == s[11])).expect("no share"));
+        let xs = vec![mixed[3][11]];
+        assert_eq!(combine_checked(&mixed, 3), Err(CombineError::InconsistentShares { xs }));
+
+        // Not enough shares to reach the threshold.
+        assert_eq!(combine_checked(&shares[..2], 3), Err(CombineError::TooFewShares { needed: 3, found: 2 }));
+    }
+
     // The 'combine' function with invalid or insufficient shares.
     #[test]
     fn it_fails_to_combine_invalid_shares_input() {
         // Inconsistent shares
         let shares = vec![vec![1, 2], vec![3, 4, 3]];
-        assert!(combine(shares).is_err());
+        assert_eq!(
+            combine(shares),
+            Err(CombineError::LengthMismatch { index: 1, expected: 2, found: 3 })
+        );
 
         // Invalid number of shares
         let shares = vec![vec![1, 2]];
-        assert!(combine(shares).is_err());
+        assert_eq!(combine(shares), Err(CombineError::TooFewShares { needed: 2, found: 1 }));
+
+        // Shares without any y-values
+        let shares = vec![vec![1], vec![2]];
+        assert_eq!(combine(shares), Err(CombineError::ShareTooShort { index: 0, length: 1 }));
     }
 
     // The 'combine' function with duplicate shares.
@@ -95,6 +381,6 @@ mod tests {
             vec![137, 206, 171, 244, 28, 176, 109, 4, 12, 168, 87, 50],
         ];
 
-        assert!(combine(shares).is_err());
+        assert_eq!(combine(shares), Err(CombineError::DuplicateX { x: 50, first: 1, second: 2 }));
     }
 }
\ No newline at end of file
@@ -0,0 +1,335 @@
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+
+use zeroize::Zeroizing;
+
+use crate::share::{Share, ShareError};
+use crate::wordlist::WORDLIST;
+
+/// The number of bits carried by each word.
+pub(crate) const RADIX_BITS: u32 = 10;
+
+/// The number of checksum words at the end of every mnemonic.
+pub(crate) const CHECKSUM_WORDS: usize = 3;
+
+/// The customization string of the checksum, which differs from SLIP-39 so the two kinds of mnemonics cannot be mixed up.
+const CUSTOMIZATION: &[u8] = b"shamir-share";
+
+/// The generator of the RS1024 checksum from SLIP-39.
+const GENERATOR: [u32; 10] = [
+    0x00E0_E040,
+    0x01C1_C080,
+    0x0383_8100,
+    0x0707_0200,
+    0x0E0E_0009,
+    0x1C0C_2412,
+    0x3808_6C24,
+    0x3090_FC48,
+    0x21B1_F890,
+    0x03F3_F120,
+];
+
+/// The maximum number of y-values of a share that can be encoded as a mnemonic.
+pub const MAX_MNEMONIC_SECRET_LEN: usize = (1 << RADIX_BITS) - 1;
+
+/// An error returned when a share cannot be encoded as, or decoded from, a mnemonic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MnemonicError {
+    /// The share has more y-values than fit the length word.
+    TooLong {
+        /// The number of y-values of the share.
+        length: usize,
+    },
+    /// A word is not in the wordlist, nor an unambiguous prefix of one.
+    UnknownWord {
+        /// The position of the word in the mnemonic, counted in words.
+        position: usize,
+        /// The offending word.
+        word: String,
+    },
+    /// The number of words does not match the length encoded in the mnemonic.
+    InvalidLength {
+        /// The number of words.
+        words: usize,
+    },
+    /// The checksum words do not match, so at least one word was mistyped.
+    InvalidChecksum,
+    /// The unused bits of the last data word are not zero.
+    InvalidPadding,
+    /// The decoded bytes do not form a valid share.
+    InvalidShare(ShareError),
+}
+
+impl core::fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MnemonicError::TooLong { length } => {
+                write!(f, "share of {} bytes exceeds the maximum of {} bytes", length, MAX_MNEMONIC_SECRET_LEN)
+            }
+            MnemonicError::UnknownWord { position, word } => write!(f, "unknown word {:?} at position {}", word, position),
+            MnemonicError::InvalidLength { words } => write!(f, "invalid mnemonic length of {} words", words),
+            MnemonicError::InvalidChecksum => write!(f, "invalid mnemonic checksum"),
+            MnemonicError::InvalidPadding => write!(f, "invalid mnemonic padding"),
+            MnemonicError::InvalidShare(error) => write!(f, "invalid share: {}", error),
+        }
+    }
+}
+
+impl core::error::Error for MnemonicError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MnemonicError::InvalidShare(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ShareError> for MnemonicError {
+    fn from(error: ShareError) -> Self {
+        MnemonicError::InvalidShare(error)
+    }
+}
+
+impl Share {
+    /// Encodes the share as a mnemonic of words from the SLIP-39 wordlist.
+    ///
+    /// The first word holds the number of y-values, followed by the y-values and the x-coordinate
+    /// at 10 bits per word, and three checksum words. The RS1024 checksum detects any
+    /// three mistyped words.
+    ///
+    /// ## Returns
+    /// * The words separated by single spaces.
+    ///
+    /// ## Errors
+    /// * [`MnemonicError::TooLong`] if the share has more than [`MAX_MNEMONIC_SECRET_LEN`] y-values.
+    pub fn to_mnemonic(&self) -> Result<String, MnemonicError> {
+        let length = self.y_values().len();
+        if length > MAX_MNEMONIC_SECRET_LEN {
+            return Err(MnemonicError::TooLong { length });
+        }
+
+        let mut indices = Zeroizing::new(vec![length as u16]);
+        indices.extend(pack(self.as_bytes()));
+        let checksum = rs1024_checksum(CUSTOMIZATION, &indices);
+        indices.extend(checksum);
+
+        let words: Vec<&str> = indices.iter().map(|&i| WORDLIST[i as usize]).collect();
+        Ok(words.join(" "))
+    }
+
+    /// Decodes a share from a mnemonic created by [`Share::to_mnemonic`].
+    ///
+    /// Words are separated by any whitespace, matched regardless of case,
+    /// and may be shortened to their first four letters.
+    ///
+    /// ## Errors
+    /// * [`MnemonicError::UnknownWord`] if a word is not in the wordlist.
+    /// * [`MnemonicError::InvalidChecksum`] if a word was mistyped.
+    /// * [`MnemonicError::InvalidLength`] or [`MnemonicError::InvalidPadding`] if the mnemonic is malformed.
+    /// * [`MnemonicError::InvalidShare`] if the decoded bytes do not form a share.
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Share, MnemonicError> {
+        let indices = parse_words(mnemonic)?;
+
+        // The length word, at least one data word and the checksum words.
+        if indices.len() < CHECKSUM_WORDS + 2 {
+            return Err(MnemonicError::InvalidLength { words: indices.len() });
+        }
+        if !rs1024_verify(CUSTOMIZATION, &indices) {
+            return Err(MnemonicError::InvalidChecksum);
+        }
+
+        // The y-values and the x-coordinate.
+        let length = indices[0] as usize + 1;
+        let data = &indices[1..indices.len() - CHECKSUM_WORDS];
+        if data.len() != (length * 8).div_ceil(RADIX_BITS as usize) {
+            return Err(MnemonicError::InvalidLength { words: indices.len() });
+        }
+
+        let bytes = unpack(data, length).ok_or(MnemonicError::InvalidPadding)?;
+        Ok(Share::try_from(bytes)?)
+    }
+}
+
+/// Looks up the words of a mnemonic in the wordlist.
+///
+/// ## Errors
+/// * [`MnemonicError::UnknownWord`] if a word is neither in the wordlist nor a prefix of at least four letters of one.
+pub(crate) fn parse_words(mnemonic: &str) -> Result<Zeroizing<Vec<u16>>, MnemonicError> {
+    let mut indices = Zeroizing::new(Vec::new());
+    for (position, word) in mnemonic.split_whitespace().enumerate() {
+        let index = word_index(word).ok_or_else(|| MnemonicError::UnknownWord { position, word: word.to_owned() })?;
+        indices.push(index);
+    }
+    Ok(indices)
+}
+
+/// Returns the index of a word in the wordlist, accepting any case and prefixes of at least four letters.
+fn word_index(word: &str) -> Option<u16> {
+    let word = word.to_ascii_lowercase();
+    // Every word is ASCII, which also keeps the slicing below on character boundaries.
+    if word.len() < 4 || !word.is_ascii() {
+        return None;
+    }
+    // The first four letters identify a word, so a prefix can only be of that one.
+    let position = WORDLIST.binary_search_by(|w| w[..4].cmp(&word[..4])).ok()?;
+    WORDLIST[position].starts_with(&word).then_some(position as u16)
+}
+
+/// Packs bytes into words of 10 bits as a big-endian bit stream, padding the last word with zeros.
+fn pack(bytes: &[u8]) -> Vec<u16> {
+    let mask = (1u32 << RADIX_BITS) - 1;
+    let mut words = Vec::with_capacity((bytes.len() * 8).div_ceil(RADIX_BITS as usize));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        if bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            words.push(((buffer >> bits) & mask) as u16);
+        }
+    }
+    if bits > 0 {
+        words.push(((buffer << (RADIX_BITS - bits)) & mask) as u16);
+    }
+    words
+}
+
+/// Unpacks `length` bytes from words of 10 bits, as packed by [`pack`].
+///
+/// ## Returns
+/// * The bytes, or `None` if any of the padding bits after them is not zero.
+fn unpack(words: &[u16], length: usize) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(length);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+
+    for &word in words {
+        buffer = (buffer << RADIX_BITS) | word as u32;
+        bits += RADIX_BITS;
+        while bits >= 8 && bytes.len() < length {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    (buffer & ((1 << bits) - 1) == 0).then_some(bytes)
+}
+
+/// Computes the RS1024 remainder of the values, as specified by SLIP-39.
+fn rs1024_polymod(values: impl IntoIterator<Item = u16>) -> u32 {
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 20;
+        checksum = ((checksum & 0xF_FFFF) << RADIX_BITS) ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// Computes the RS1024 checksum words of the data under a customization string.
+pub(crate) fn rs1024_checksum(customization: &[u8], data: &[u16]) -> [u16; CHECKSUM_WORDS] {
+    let values = customization.iter().map(|&c| c as u16).chain(data.iter().copied()).chain([0; CHECKSUM_WORDS]);
+    let polymod = rs1024_polymod(values) ^ 1;
+    [2, 1, 0].map(|i| ((polymod >> (RADIX_BITS * i)) & 0x3FF) as u16)
+}
+
+/// Verifies the RS1024 checksum words at the end of the data under a customization string.
+pub(crate) fn rs1024_verify(customization: &[u8], data: &[u16]) -> bool {
+    rs1024_polymod(customization.iter().map(|&c| c as u16).chain(data.iter().copied())) == 1
+}
+
+// Test cases for the mnemonic encoding.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    // The wordlist is sorted and every word is identified by its first four letters.
+    #[test]
+    fn it_has_unique_prefixes() {
+        for pair in WORDLIST.windows(2) {
+            assert!(pair[0][..4] < pair[1][..4], "{} and {} are not ordered", pair[0], pair[1]);
+        }
+        assert!(WORDLIST.iter().all(|w| (4..=8).contains(&w.len())));
+    }
+
+    // A known SLIP-39 mnemonic has a valid checksum under the SLIP-39 customization string.
+    #[test]
+    fn it_verifies_slip39_checksum() {
+        let mnemonic = "duckling enlarge academic academic agency result length solution fridge kidney \
+                        coal piece deal husband erode duke ajar critical decision keyboard";
+        let indices = parse_words(mnemonic).expect("parse failed");
+        assert!(rs1024_verify(b"shamir", &indices));
+        assert!(!rs1024_verify(CUSTOMIZATION, &indices));
+
+        let checksum = rs1024_checksum(b"shamir", &indices[..indices.len() - CHECKSUM_WORDS]);
+        assert_eq!(checksum[..], indices[indices.len() - CHECKSUM_WORDS..]);
+    }
+
+    // Shares of every length around a full block round-trip through a mnemonic.
+    #[test]
+    fn it_round_trips() {
+        let y_values: Vec<u8> = (0..=255).rev().collect();
+        for len in 1..=12 {
+            let share = Share::new(42, &y_values[..len]).expect("invalid share");
+            let mnemonic = share.to_mnemonic().expect("encode failed");
+            assert_eq!(mnemonic.split(' ').count(), 1 + ((len + 1) * 8).div_ceil(10) + CHECKSUM_WORDS);
+            assert_eq!(Share::from_mnemonic(&mnemonic), Ok(share));
+        }
+    }
+
+    // Decoding tolerates case, extra whitespace and four-letter prefixes.
+    #[test]
+    fn it_decodes_leniently() {
+        let share = Share::new(3, b"steel plate").expect("invalid share");
+        let mnemonic = share.to_mnemonic().expect("encode failed");
+
+        let sloppy: Vec<String> = mnemonic
+            .split(' ')
+            .enumerate()
+            .map(|(i, w)| match i % 3 {
+                0 => w.to_uppercase(),
+                1 => w[..4].to_owned(),
+                _ => w.to_owned(),
+            })
+            .collect();
+        let sloppy = format!("  {}\n", sloppy.join(" \t\n "));
+        assert_eq!(Share::from_mnemonic(&sloppy), Ok(share));
+    }
+
+    // Unknown words and prefixes that are too short or do not match are rejected.
+    #[test]
+    fn it_rejects_unknown_words() {
+        assert_eq!(word_index("acad"), Some(0));
+        assert_eq!(word_index("Zero"), Some(1023));
+        assert_eq!(word_index("aca"), None);
+        assert_eq!(word_index("academy"), None);
+        assert_eq!(word_index("academics"), None);
+        assert_eq!(word_index("acé"), None);
+
+        assert_eq!(
+            Share::from_mnemonic("academic acid bogus"),
+            Err(MnemonicError::UnknownWord { position: 2, word: "bogus".into() })
+        );
+    }
+
+    // A mistyped word or a truncated mnemonic is detected.
+    #[test]
+    fn it_detects_errors() {
+        let share = Share::new(9, b"secret").expect("invalid share");
+        let mnemonic = share.to_mnemonic().expect("encode failed");
+        let words: Vec<&str> = mnemonic.split(' ').collect();
+
+        let mut typo = words.clone();
+        typo[2] = if typo[2] == "academic" { "acid" } else { "academic" };
+        assert_eq!(Share::from_mnemonic(&typo.join(" ")), Err(MnemonicError::InvalidChecksum));
+
+        assert_eq!(Share::from_mnemonic(&words[..4].join(" ")), Err(MnemonicError::InvalidLength { words: 4 }));
+        assert_eq!(Share::from_mnemonic(&words[1..].join(" ")), Err(MnemonicError::InvalidChecksum));
+
+        let long = Share::new(1, &[0; MAX_MNEMONIC_SECRET_LEN + 1]).expect("invalid share");
+        assert_eq!(long.to_mnemonic(), Err(MnemonicError::TooLong { length: MAX_MNEMONIC_SECRET_LEN + 1 }));
+    }
+}
@@ -1,5 +1,21 @@
 use subtle::{ConditionallySelectable, ConstantTimeEq};
 
+pub(crate) mod bitslice;
+pub(crate) mod simd;
+#[cfg(feature = "gf-tables")]
+mod tables;
+
+// The `gf-tables` feature replaces the bit-serial multiplication and inversion
+// with log/exp tables read through full-table scans.
+#[cfg(feature = "gf-tables")]
+pub(crate) use tables::mult;
+#[cfg(feature = "gf-tables")]
+use tables::inverse;
+#[cfg(not(feature = "gf-tables"))]
+pub(crate) use self::mult_serial as mult;
+#[cfg(not(feature = "gf-tables"))]
+use self::inverse_serial as inverse;
+
 /// The irreducible polynomial in `GF(2^8)`.
 const IRREDUCIBLE_POLYNOMIAL: u8 = 0x1B;
 
@@ -57,7 +73,8 @@ pub(crate) fn div(a: u8, b: u8) -> u8 {
 //
 // The `#[inline(never)]` attribute prevents that from happening.
 #[inline(never)]
-pub(crate) fn mult(a: u8, b: u8) -> u8 {
+#[cfg(any(test, not(feature = "gf-tables")))]
+pub(crate) fn mult_serial(a: u8, b: u8) -> u8 {
     let mut product = 0u8;
 
     for i in (0..8).rev() {
@@ -107,7 +124,8 @@ pub(crate) fn add(a: u8, b: u8) -> u8 {
 ///
 /// ## Panics
 /// * If `a` is zero, since the inverse of zero is undefined.
-fn inverse(a: u8) -> u8 {
+#[cfg(any(test, not(feature = "gf-tables")))]
+fn inverse_serial(a: u8) -> u8 {
     if a == 0 {
         // As a conditional, it may provide side-channel (timing) based information,
         // although the inverse of zero is undefined therefor it`s just a safe-guard
@@ -125,12 +143,12 @@ fn inverse(a: u8) -> u8 {
         // 4th iteration: b = a^30 then b= a^31
         // 5th iteration: b = a^62 then b= a^63
         // 6th iteration: b = a^126 then b= a^127
-        b = mult(b, b);
-        b = mult(b, a);
+        b = mult_serial(b, b);
+        b = mult_serial(b, a);
     }
 
     // finalization: b = a^254 -> a^-1
-    mult(b, b)
+    mult_serial(b, b)
 }
 
 // Tests for basic arithmetic operation in `GF(2^8)`.
@@ -0,0 +1,178 @@
+use zeroize::Zeroize;
+
+/// The number of field elements held by a [`Bitsliced`] value.
+pub(crate) const LANES: usize = 64;
+
+/// 64 elements of `GF(2^8)` in bitsliced form: word `i` holds bit `i` of every element,
+/// with the element of lane `j` at bit `j`.
+///
+/// Addition and multiplication operate on all lanes at once using only `AND` and `XOR`,
+/// so there are no branches or memory accesses that depend on the elements.
+#[derive(Clone, Default)]
+pub(crate) struct Bitsliced([u64; 8]);
+
+impl Bitsliced {
+    /// Creates a value with `a` in every lane.
+    #[inline]
+    pub(crate) fn broadcast(a: u8) -> Bitsliced {
+        let mut slices = [0u64; 8];
+        for (i, slice) in slices.iter_mut().enumerate() {
+            // All ones if bit `i` of `a` is set, all zeros otherwise.
+            *slice = (((a >> i) & 1) as u64).wrapping_neg();
+        }
+        Bitsliced(slices)
+    }
+
+    /// Loads up to [`LANES`] elements, leaving the remaining lanes zero.
+    ///
+    /// ## Panics
+    /// * If `bytes` holds more than [`LANES`] elements.
+    #[inline]
+    pub(crate) fn load(bytes: &[u8]) -> Bitsliced {
+        assert!(bytes.len() <= LANES, "too many elements");
+
+        let mut slices = [0u64; 8];
+        for (k, chunk) in bytes.chunks(8).enumerate() {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            // Byte `i` of the transposed word holds bit `i` of the eight elements of this chunk.
+            let mut transposed = transpose(u64::from_le_bytes(word));
+            for (i, slice) in slices.iter_mut().enumerate() {
+                *slice |= ((transposed >> (8 * i)) & 0xFF) << (8 * k);
+            }
+            word.zeroize();
+            transposed.zeroize();
+        }
+        Bitsliced(slices)
+    }
+
+    /// Stores the first `out.len()` lanes into `out`.
+    ///
+    /// ## Panics
+    /// * If `out` is longer than [`LANES`].
+    #[inline]
+    pub(crate) fn store(&self, out: &mut [u8]) {
+        assert!(out.len() <= LANES, "too many elements");
+
+        for (k, chunk) in out.chunks_mut(8).enumerate() {
+            let mut transposed = 0u64;
+            for (i, slice) in self.0.iter().enumerate() {
+                transposed |= ((slice >> (8 * k)) & 0xFF) << (8 * i);
+            }
+            let mut word = transpose(transposed).to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+            word.zeroize();
+            transposed.zeroize();
+        }
+    }
+
+    /// Performs addition (`XOR`) lane by lane.
+    #[inline]
+    pub(crate) fn add(&self, other: &Bitsliced) -> Bitsliced {
+        let mut slices = [0u64; 8];
+        for (i, slice) in slices.iter_mut().enumerate() {
+            *slice = self.0[i] ^ other.0[i];
+        }
+        Bitsliced(slices)
+    }
+
+    /// Performs multiplication lane by lane.
+    #[inline]
+    pub(crate) fn mult(&self, other: &Bitsliced) -> Bitsliced {
+        // Schoolbook multiplication of the two polynomials of degree 7,
+        // where the coefficient of each power is a whole word of lanes.
+        let mut product = [0u64; 15];
+        for (i, a) in self.0.iter().enumerate() {
+            for (j, b) in other.0.iter().enumerate() {
+                product[i + j] ^= a & b;
+            }
+        }
+
+        // Reduce from the highest power down, using `x^8 = x^4 + x^3 + x + 1`
+        // as given by the irreducible polynomial `0x11B`.
+        for d in (8..15).rev() {
+            let high = product[d];
+            product[d - 4] ^= high;
+            product[d - 5] ^= high;
+            product[d - 7] ^= high;
+            product[d - 8] ^= high;
+        }
+
+        let mut slices = [0u64; 8];
+        slices.copy_from_slice(&product[..8]);
+        product.zeroize();
+        Bitsliced(slices)
+    }
+}
+
+// The lanes may hold secret bytes, so they are cleared once the value is dropped.
+impl Drop for Bitsliced {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Transposes an 8×8 bit matrix stored with one row per byte, where the element
+/// at row `r` and column `c` is bit `c` of byte `r`.
+///
+/// Each step swaps the off-diagonal 1×1, 2×2 and 4×4 blocks using masks only.
+fn transpose(mut x: u64) -> u64 {
+    let t = (x ^ (x >> 7)) & 0x00AA_00AA_00AA_00AA;
+    x ^= t ^ (t << 7);
+    let t = (x ^ (x >> 14)) & 0x0000_CCCC_0000_CCCC;
+    x ^= t ^ (t << 14);
+    let t = (x ^ (x >> 28)) & 0x0000_0000_F0F0_F0F0;
+    x ^= t ^ (t << 28);
+    x
+}
+
+// Tests for the bitsliced arithmetic.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use crate::ops;
+
+    // Loading and storing round-trips any number of lanes.
+    #[test]
+    fn it_loads_and_stores() {
+        let bytes: Vec<u8> = (0..LANES as u8).map(|b| b.wrapping_mul(37) ^ 0xA5).collect();
+        for len in [0, 1, 7, 8, 9, 63, 64] {
+            let mut out = vec![0u8; len];
+            Bitsliced::load(&bytes[..len]).store(&mut out);
+            assert_eq!(out, bytes[..len]);
+        }
+
+        // Each word holds one bit of every lane.
+        let sliced = Bitsliced::load(&[0x01, 0x80, 0x81]);
+        assert_eq!(sliced.0, [0b101, 0, 0, 0, 0, 0, 0, 0b110]);
+    }
+
+    // Every lane of a broadcast value holds the same element.
+    #[test]
+    fn it_broadcasts() {
+        let mut out = [0u8; LANES];
+        Bitsliced::broadcast(0xC3).store(&mut out);
+        assert_eq!(out, [0xC3; LANES]);
+    }
+
+    // The bitsliced operations agree with the scalar ones for every pair of operands.
+    #[test]
+    fn it_matches_scalar_ops() {
+        let all: Vec<u8> = (0..=255).collect();
+        for a in 0..=255 {
+            let broadcast = Bitsliced::broadcast(a);
+            for chunk in all.chunks(LANES) {
+                let b = Bitsliced::load(chunk);
+                let mut product = [0u8; LANES];
+                let mut sum = [0u8; LANES];
+                broadcast.mult(&b).store(&mut product);
+                broadcast.add(&b).store(&mut sum);
+                for (i, &b) in chunk.iter().enumerate() {
+                    assert_eq!(product[i], ops::mult(a, b), "{:#04x} * {:#04x}", a, b);
+                    assert_eq!(sum[i], ops::add(a, b));
+                }
+            }
+        }
+    }
+}
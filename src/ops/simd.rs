@@ -0,0 +1,274 @@
+#[cfg(test)]
+use alloc::vec::Vec;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::ops::bitslice::{Bitsliced, LANES};
+
+/// Detects CPU features at runtime with `std`, or falls back to the features enabled at compile time.
+#[cfg(target_arch = "x86_64")]
+macro_rules! detected {
+    ($($feature:tt),+) => {{
+        #[cfg(feature = "std")]
+        let detected = $(std::is_x86_feature_detected!($feature))&&+;
+        #[cfg(not(feature = "std"))]
+        let detected = cfg!(all($(target_feature = $feature),+));
+        detected
+    }};
+}
+
+/// An implementation of the bulk operations over byte slices.
+///
+/// Every backend produces bit-identical results; they only differ in speed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Backend {
+    /// The bitsliced arithmetic of [`Bitsliced`], available on every target.
+    Portable,
+    /// `pshufb` lookups in nibble tables held in registers, 16 bytes at a time.
+    Ssse3,
+    /// `vpshufb` lookups in nibble tables held in registers, 32 bytes at a time.
+    Avx2,
+    /// `vgf2p8mulb`, which multiplies in `GF(2^8)` with the same irreducible polynomial `0x11B`, 32 bytes at a time.
+    #[cfg(feature = "gfni")]
+    Gfni,
+}
+
+impl Backend {
+    /// All backends, from the slowest to the fastest.
+    const ALL: &'static [Backend] = &[
+        Backend::Portable,
+        Backend::Ssse3,
+        Backend::Avx2,
+        #[cfg(feature = "gfni")]
+        Backend::Gfni,
+    ];
+
+    /// Returns the fastest backend supported by the CPU, detected at runtime.
+    pub(crate) fn detect() -> Backend {
+        Backend::ALL.iter().rev().copied().find(|backend| backend.is_supported()).unwrap_or(Backend::Portable)
+    }
+
+    /// Returns every backend supported by the CPU, so that tests can force each code path.
+    #[cfg(test)]
+    pub(crate) fn available() -> Vec<Backend> {
+        Backend::ALL.iter().copied().filter(|backend| backend.is_supported()).collect()
+    }
+
+    /// Returns `true` if the CPU supports the instructions used by the backend.
+    fn is_supported(self) -> bool {
+        match self {
+            Backend::Portable => true,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Ssse3 => detected!("ssse3"),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => detected!("avx2"),
+            #[cfg(all(target_arch = "x86_64", feature = "gfni"))]
+            Backend::Gfni => detected!("gfni", "avx2"),
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => false,
+        }
+    }
+}
+
+/// Multiplication by a constant in `GF(2^8)`, prepared for a backend.
+///
+/// The constant is expected to be public, such as an x-coordinate or a Lagrange basis value,
+/// while the bytes it multiplies may be secret and are processed without data-dependent
+/// branches or memory accesses on every backend.
+pub(crate) struct Multiplier {
+    /// The backend performing the multiplications.
+    backend: Backend,
+    /// The constant itself.
+    #[cfg_attr(not(all(target_arch = "x86_64", feature = "gfni")), allow(dead_code))]
+    constant: u8,
+    /// The constant in every lane, for the portable backend and the tail of the others.
+    broadcast: Bitsliced,
+    /// The products of the constant with every low nibble, followed by every high nibble.
+    #[cfg_attr(not(target_arch = "x86_64"), allow(dead_code))]
+    tables: [u8; 32],
+}
+
+impl Multiplier {
+    /// Prepares multiplication by `constant` with `backend`.
+    ///
+    /// ## Panics
+    /// * If the CPU does not support the backend.
+    pub(crate) fn new(backend: Backend, constant: u8) -> Multiplier {
+        assert!(backend.is_supported(), "{:?} is not supported by the CPU", backend);
+
+        let broadcast = Bitsliced::broadcast(constant);
+        let mut nibbles = [0u8; 32];
+        for i in 0..16 {
+            nibbles[i] = i as u8;
+            nibbles[16 + i] = (i as u8) << 4;
+        }
+        let mut tables = [0u8; 32];
+        broadcast.mult(&Bitsliced::load(&nibbles)).store(&mut tables);
+
+        Multiplier { backend, constant, broadcast, tables }
+    }
+
+    /// Multiplies each byte of `src` by the constant and adds the product to the same byte of `dst`.
+    ///
+    /// ## Panics
+    /// * If `dst` and `src` differ in length.
+    pub(crate) fn mul_add(&self, dst: &mut [u8], src: &[u8]) {
+        assert_eq!(dst.len(), src.len(), "mismatched lengths");
+
+        // The number of leading bytes handled by the vector instructions.
+        let done = match self.backend {
+            Backend::Portable => 0,
+            // SAFETY: `new` ensures the CPU supports the instructions of the backend.
+            #[cfg(target_arch = "x86_64")]
+            Backend::Ssse3 => unsafe { mul_add_ssse3(&self.tables, dst, src) },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => unsafe { mul_add_avx2(&self.tables, dst, src) },
+            #[cfg(all(target_arch = "x86_64", feature = "gfni"))]
+            Backend::Gfni => unsafe { mul_add_gfni(self.constant, dst, src) },
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => unreachable!("unsupported backend"),
+        };
+
+        // The remaining bytes use the bitsliced arithmetic.
+        for (d, s) in dst[done..].chunks_mut(LANES).zip(src[done..].chunks(LANES)) {
+            let product = Bitsliced::load(s).mult(&self.broadcast);
+            Bitsliced::load(d).add(&product).store(d);
+        }
+    }
+}
+
+/// Multiplies and adds 16 bytes at a time by looking up the products of each nibble.
+///
+/// ## Returns
+/// * The number of leading bytes processed, a multiple of 16.
+///
+/// ## Safety
+/// * The CPU must support SSSE3.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn mul_add_ssse3(tables: &[u8; 32], dst: &mut [u8], src: &[u8]) -> usize {
+    // SAFETY: `tables` holds 32 bytes and unaligned loads are used.
+    let (low, high) = unsafe {
+        (_mm_loadu_si128(tables.as_ptr().cast()), _mm_loadu_si128(tables[16..].as_ptr().cast()))
+    };
+    let mask = _mm_set1_epi8(0x0F);
+
+    let mut done = 0;
+    for (d, s) in dst.chunks_exact_mut(16).zip(src.chunks_exact(16)) {
+        // SAFETY: both chunks hold exactly 16 bytes and unaligned loads and stores are used.
+        unsafe {
+            let s = _mm_loadu_si128(s.as_ptr().cast());
+            let product = _mm_xor_si128(
+                _mm_shuffle_epi8(low, _mm_and_si128(s, mask)),
+                _mm_shuffle_epi8(high, _mm_and_si128(_mm_srli_epi64(s, 4), mask)),
+            );
+            let sum = _mm_xor_si128(_mm_loadu_si128(d.as_ptr().cast()), product);
+            _mm_storeu_si128(d.as_mut_ptr().cast(), sum);
+        }
+        done += 16;
+    }
+    done
+}
+
+/// Multiplies and adds 32 bytes at a time by looking up the products of each nibble.
+///
+/// ## Returns
+/// * The number of leading bytes processed, a multiple of 32.
+///
+/// ## Safety
+/// * The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_add_avx2(tables: &[u8; 32], dst: &mut [u8], src: &[u8]) -> usize {
+    // SAFETY: `tables` holds 32 bytes and unaligned loads are used.
+    let (low, high) = unsafe {
+        (
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(tables.as_ptr().cast())),
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(tables[16..].as_ptr().cast())),
+        )
+    };
+    let mask = _mm256_set1_epi8(0x0F);
+
+    let mut done = 0;
+    for (d, s) in dst.chunks_exact_mut(32).zip(src.chunks_exact(32)) {
+        // SAFETY: both chunks hold exactly 32 bytes and unaligned loads and stores are used.
+        unsafe {
+            let s = _mm256_loadu_si256(s.as_ptr().cast());
+            let product = _mm256_xor_si256(
+                _mm256_shuffle_epi8(low, _mm256_and_si256(s, mask)),
+                _mm256_shuffle_epi8(high, _mm256_and_si256(_mm256_srli_epi64(s, 4), mask)),
+            );
+            let sum = _mm256_xor_si256(_mm256_loadu_si256(d.as_ptr().cast()), product);
+            _mm256_storeu_si256(d.as_mut_ptr().cast(), sum);
+        }
+        done += 32;
+    }
+    done
+}
+
+/// Multiplies and adds 32 bytes at a time with the GFNI multiplication.
+///
+/// ## Returns
+/// * The number of leading bytes processed, a multiple of 32.
+///
+/// ## Safety
+/// * The CPU must support GFNI and AVX2.
+// The GFNI intrinsics are stable since Rust 1.89, which the `gfni` feature requires.
+#[cfg(all(target_arch = "x86_64", feature = "gfni"))]
+#[allow(clippy::incompatible_msrv)]
+#[target_feature(enable = "gfni,avx2")]
+unsafe fn mul_add_gfni(constant: u8, dst: &mut [u8], src: &[u8]) -> usize {
+    let constant = _mm256_set1_epi8(constant as i8);
+
+    let mut done = 0;
+    for (d, s) in dst.chunks_exact_mut(32).zip(src.chunks_exact(32)) {
+        // SAFETY: both chunks hold exactly 32 bytes and unaligned loads and stores are used.
+        unsafe {
+            let product = _mm256_gf2p8mul_epi8(_mm256_loadu_si256(s.as_ptr().cast()), constant);
+            let sum = _mm256_xor_si256(_mm256_loadu_si256(d.as_ptr().cast()), product);
+            _mm256_storeu_si256(d.as_mut_ptr().cast(), sum);
+        }
+        done += 32;
+    }
+    done
+}
+
+// Tests for the bulk operations of each backend.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use crate::ops;
+    use rand::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    // The fastest supported backend is detected.
+    #[test]
+    fn it_detects_backend() {
+        let available = Backend::available();
+        assert_eq!(available[0], Backend::Portable);
+        assert_eq!(Backend::detect(), *available.last().expect("no backend"));
+    }
+
+    // Every backend agrees with the scalar arithmetic for every constant, including the tails.
+    #[test]
+    fn it_matches_scalar_ops() {
+        let mut rng = ChaCha20Rng::from_seed([1; 32]);
+        for backend in Backend::available() {
+            for len in [0, 1, 15, 16, 17, 31, 32, 33, 64, 100, 261] {
+                let mut src = vec![0u8; len];
+                let mut dst = vec![0u8; len];
+                rng.fill_bytes(&mut src);
+                rng.fill_bytes(&mut dst);
+
+                for constant in 0..=255 {
+                    let expected: Vec<u8> =
+                        dst.iter().zip(&src).map(|(&d, &s)| ops::add(d, ops::mult(constant, s))).collect();
+                    let mut actual = dst.clone();
+                    Multiplier::new(backend, constant).mul_add(&mut actual, &src);
+                    assert_eq!(actual, expected, "{:?} with {:#04x} over {} bytes", backend, constant, len);
+                }
+            }
+        }
+    }
+}
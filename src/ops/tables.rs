@@ -0,0 +1,129 @@
+/// The generator of the multiplicative group of `GF(2^8)`, i.e. `x + 1`.
+const GENERATOR: u8 = 0x03;
+
+/// The logarithms to the base of the generator, packed eight per little-endian word.
+///
+/// The logarithm of zero is undefined and stored as zero.
+const LOG: [u64; 32] = pack(&log_table());
+
+/// The powers of the generator, repeated so that the sum of two logarithms needs no reduction.
+const EXP: [u64; 64] = pack(&exp_table());
+
+/// Performs multiplication of two `u8` values in `GF(2^8)` as `exp(log(a) + log(b))`.
+///
+/// Every lookup scans the whole table, so the memory access pattern does not depend on the operands.
+#[inline]
+pub(crate) fn mult(a: u8, b: u8) -> u8 {
+    let log_a = lookup(&LOG, a as usize) as usize;
+    let log_b = lookup(&LOG, b as usize) as usize;
+    let product = lookup(&EXP, log_a + log_b);
+
+    // Zero has no logarithm, so the product is cleared if either operand is zero.
+    // The top bit of the negation of a non-zero byte widened to `u32` is set.
+    let nonzero = ((a as u32).wrapping_neg() & (b as u32).wrapping_neg()) >> 31;
+    product & (nonzero as u8).wrapping_neg()
+}
+
+/// Computes the multiplicative inverse of a value in `GF(2^8)` as `exp(255 - log(a))`.
+///
+/// ## Panics
+/// * If `a` is zero, since the inverse of zero is undefined.
+pub(crate) fn inverse(a: u8) -> u8 {
+    if a == 0 {
+        // As a conditional, it may provide side-channel (timing) based information,
+        // although the inverse of zero is undefined therefor it`s just a safe-guard
+        // and should never occur.
+        panic!("inverse of zero is undefined");
+    }
+
+    lookup(&EXP, 255 - lookup(&LOG, a as usize) as usize)
+}
+
+/// Reads the byte at `index` of a packed table, touching every word of the table.
+#[inline]
+fn lookup<const N: usize>(table: &[u64; N], index: usize) -> u8 {
+    let target = (index / 8) as u64;
+    let mut word = 0;
+    for (i, &w) in table.iter().enumerate() {
+        // The mask is all ones for the target word and zero otherwise, computed without branching:
+        // the difference is only zero for the target, and subtracting one from zero sets the top bit.
+        let mask = (((i as u64) ^ target).wrapping_sub(1) >> 63).wrapping_neg();
+        word |= w & mask;
+    }
+    (word >> (8 * (index % 8))) as u8
+}
+
+/// Multiplies two values in `GF(2^8)` at compile time, in the same way as [`super::mult_serial`].
+const fn const_mult(a: u8, b: u8) -> u8 {
+    let mut product = 0u8;
+    let mut i = 8;
+    while i > 0 {
+        i -= 1;
+        let contribution = (b >> i) & 1;
+        let reduction = (product >> 7) & 1;
+        product = (product << 1) ^ (contribution * a) ^ (reduction * super::IRREDUCIBLE_POLYNOMIAL);
+    }
+    product
+}
+
+/// Computes the powers of the generator for exponents `0..512`, wrapping around after 255.
+const fn exp_table() -> [u8; 512] {
+    let mut table = [0u8; 512];
+    let mut power = 1u8;
+    let mut i = 0;
+    while i < 512 {
+        table[i] = power;
+        power = const_mult(power, GENERATOR);
+        i += 1;
+    }
+    table
+}
+
+/// Computes the logarithms to the base of the generator.
+const fn log_table() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut power = 1u8;
+    let mut i = 0;
+    while i < 255 {
+        table[power as usize] = i as u8;
+        power = const_mult(power, GENERATOR);
+        i += 1;
+    }
+    table
+}
+
+/// Packs a table into little-endian words of eight bytes.
+const fn pack<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u64; M] {
+    let mut words = [0u64; M];
+    let mut i = 0;
+    while i < N {
+        words[i / 8] |= (bytes[i] as u64) << (8 * (i % 8));
+        i += 1;
+    }
+    words
+}
+
+// Tests for the table-driven arithmetic.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::{inverse_serial, mult_serial};
+
+    // The tables agree with the bit-serial implementation for every pair of operands.
+    #[test]
+    fn it_matches_bit_serial_mult() {
+        for a in 0..=255 {
+            for b in 0..=255 {
+                assert_eq!(mult(a, b), mult_serial(a, b), "{:#04x} * {:#04x}", a, b);
+            }
+        }
+    }
+
+    // The tables agree with the bit-serial implementation for every inverse.
+    #[test]
+    fn it_matches_bit_serial_inverse() {
+        for a in 1..=255 {
+            assert_eq!(inverse(a), inverse_serial(a), "{:#04x}^-1", a);
+        }
+    }
+}
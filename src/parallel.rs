@@ -0,0 +1,171 @@
+use alloc::{vec, vec::Vec};
+
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use rayon::prelude::*;
+use zeroize::Zeroizing;
+
+use crate::combine::{x_samples, CombineError, CombineSecret};
+use crate::polynomial::Polynomial;
+use crate::share::Share;
+use crate::split::{check_parameters, evaluate_into, random_x_coordinates, Secret, SplitError};
+
+/// The number of secret bytes processed by each parallel task.
+const CHUNK_LEN: usize = 64 * 1024;
+
+/// Splits a secret into multiple shares, processing chunks of the secret in parallel.
+///
+/// The shares can be combined with [`combine`](crate::combine) or [`par_combine`] alike,
+/// but differ from the shares [`split`](crate::split) would create from the same randomness.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+pub fn par_split<T: Secret>(secret: T, parts: usize, threshold: usize) -> Result<Vec<Share>, SplitError> {
+    par_split_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares in parallel, drawing all randomness from `rng`.
+///
+/// The generator is only used on the calling thread: it shuffles the x-coordinates and then
+/// draws a 32-byte seed per chunk of the secret. Each chunk generates its polynomial coefficients
+/// from its own ChaCha20 DRBG, so the result is the same regardless of how the chunks are scheduled
+/// and no generator is shared between threads.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+pub fn par_split_with_rng<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+    secret: T,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, SplitError> {
+    check_parameters(parts, threshold, secret.is_empty())?;
+    let x_coordinates = random_x_coordinates(parts, rng);
+
+    let secret = secret.iter().as_slice();
+    let seeds: Vec<Zeroizing<[u8; 32]>> = secret
+        .chunks(CHUNK_LEN)
+        .map(|_| {
+            let mut seed = Zeroizing::new([0u8; 32]);
+            rng.fill_bytes(&mut *seed);
+            seed
+        })
+        .collect();
+
+    // Initialize the shares with the x-coordinate in the last position.
+    let mut shares: Vec<Vec<u8>> = x_coordinates
+        .iter()
+        .map(|&x| {
+            let mut share = vec![0u8; secret.len() + 1];
+            share[secret.len()] = x;
+            share
+        })
+        .collect();
+
+    // Gather the part of every share that belongs to each chunk of the secret.
+    let mut columns: Vec<Vec<&mut [u8]>> = seeds.iter().map(|_| Vec::with_capacity(parts)).collect();
+    for share in shares.iter_mut() {
+        for (column, ys) in columns.iter_mut().zip(share[..secret.len()].chunks_mut(CHUNK_LEN)) {
+            column.push(ys);
+        }
+    }
+
+    // Each chunk generates its coefficients from its own generator, seeded in the order of the chunks.
+    columns
+        .into_par_iter()
+        .zip(secret.par_chunks(CHUNK_LEN))
+        .zip(seeds.par_iter())
+        .for_each(|((mut ys, chunk), seed)| {
+            let mut rng = ChaCha20Rng::from_seed(**seed);
+            evaluate_into(chunk, &x_coordinates, threshold, &mut rng, &mut ys);
+        });
+
+    Ok(shares.into_iter().map(Share::from_raw).collect())
+}
+
+/// Combines shares to reconstruct the secret, interpolating chunks of the secret in parallel.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret, either as [`Share`]s or raw bytes.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`combine`](crate::combine).
+pub fn par_combine<T: CombineSecret>(shares: T) -> Result<Vec<u8>, CombineError> {
+    // Validate the shares and gather their x-coordinates.
+    let x_samples = x_samples(&shares)?;
+
+    // The Lagrange weights only depend on the x-coordinates and are shared by every chunk.
+    let weights = Polynomial::weights(&x_samples, 0);
+    let y_samples: Vec<&[u8]> = shares.iter().map(AsRef::as_ref).collect();
+
+    let mut secret = vec![0u8; y_samples[0].len() - 1];
+    secret.par_chunks_mut(CHUNK_LEN).enumerate().for_each(|(chunk_idx, chunk)| {
+        let offset = chunk_idx * CHUNK_LEN;
+        let ys: Vec<&[u8]> = y_samples.iter().map(|y| &y[offset..offset + chunk.len()]).collect();
+        Polynomial::weighted_sum(&weights, &ys, chunk);
+    });
+
+    Ok(secret)
+}
+
+// Test cases for the parallel split and combine.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combine;
+
+    // Shares split in parallel across several chunks are combined by either function.
+    #[test]
+    fn it_splits_and_combines_in_parallel() {
+        let secret: Vec<u8> = (0..3 * CHUNK_LEN + 5).map(|i| (i % 251) as u8).collect();
+        let shares = par_split(&secret, 5, 3).expect("split failed");
+        assert_eq!(shares.len(), 5);
+
+        assert_eq!(par_combine(&shares[2..]).expect("combine failed"), secret);
+        assert_eq!(combine(&shares[..3]).expect("combine failed"), secret);
+
+        let shares = crate::split(&secret, 4, 2).expect("split failed");
+        assert_eq!(par_combine(&shares[1..3]).expect("combine failed"), secret);
+    }
+
+    // The shares only depend on the generator, not on how the chunks are scheduled.
+    #[test]
+    fn it_splits_reproducibly_with_rng() {
+        let secret = vec![0x42; CHUNK_LEN + 1];
+        let first = par_split_with_rng(&secret, 3, 2, &mut ChaCha20Rng::from_seed([3; 32])).expect("split failed");
+        let second = par_split_with_rng(&secret, 3, 2, &mut ChaCha20Rng::from_seed([3; 32])).expect("split failed");
+        assert_eq!(first, second);
+
+        // Every chunk draws its own coefficients, so equal chunks give different y-values.
+        let ys = first[0].y_values();
+        assert_ne!(ys[..1], ys[CHUNK_LEN..]);
+    }
+
+    // Invalid parameters and shares are reported as by the sequential functions.
+    #[test]
+    fn it_fails_with_invalid_input() {
+        assert_eq!(par_split("secret", 2, 3), Err(SplitError::PartsBelowThreshold { parts: 2, threshold: 3 }));
+        assert_eq!(par_split("", 3, 2), Err(SplitError::EmptySecret));
+        assert_eq!(par_combine(vec![vec![1, 2]]), Err(CombineError::TooFewShares { needed: 2, found: 1 }));
+    }
+}
@@ -0,0 +1,32 @@
+use alloc::vec::Vec;
+
+use crate::header::HEADER_VERSION;
+use crate::share::Share;
+
+/// The parameters of a split, to be stored alongside the shares.
+///
+/// With the `serde` feature, the parameters can be serialized into documents such as JSON or CBOR.
+/// The x-coordinates are serialized like a share, and deserialized parameters are validated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitParameters {
+    /// The version of the share format, as in [`HEADER_VERSION`].
+    pub version: u8,
+    /// Minimum number of shares required to reconstruct the secret.
+    pub threshold: usize,
+    /// Total number of shares created.
+    pub parts: usize,
+    /// The x-coordinates of the shares, in order.
+    pub xs: Vec<u8>,
+}
+
+impl SplitParameters {
+    /// Creates the parameters of the current format version for shares at the given x-coordinates.
+    pub fn new(threshold: usize, xs: Vec<u8>) -> SplitParameters {
+        SplitParameters { version: HEADER_VERSION, threshold, parts: xs.len(), xs }
+    }
+
+    /// Creates the parameters of the current format version from the shares of a split.
+    pub fn from_shares(threshold: usize, shares: &[Share]) -> SplitParameters {
+        SplitParameters::new(threshold, shares.iter().map(Share::x).collect())
+    }
+}
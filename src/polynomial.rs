@@ -1,6 +1,10 @@
+use alloc::{vec, vec::Vec};
+
 use crate::ops;
-use rand::Rng;
-use zeroize::Zeroize;
+use crate::ops::bitslice::{Bitsliced, LANES};
+use crate::ops::simd::{Backend, Multiplier};
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::{Zeroize, Zeroizing};
 
 /// A struct representing a polynomial with coefficients in `GF(2^8)`.
 ///
@@ -19,10 +23,11 @@ impl Polynomial {
     /// ## Arguments
     /// * `intercept` - The constant term of the polynomial.
     /// * `degree` - The highest power of `x` that appears in the polynomial.
+    /// * `rng` - The cryptographically secure source of randomness for the coefficients.
     ///
     /// ## Returns
     /// * The newly created Polynomial.
-    pub(crate) fn generate(intercept: u8, degree: u8) -> Polynomial {
+    pub(crate) fn generate<R: RngCore + CryptoRng + ?Sized>(intercept: u8, degree: u8, rng: &mut R) -> Polynomial {
         // Initialize the coefficients vector with zeros
         // in the size of the `degree`, plus 1 additional byte for the `intercept`.
         let mut coefficients = vec![0u8; (degree + 1) as usize];
@@ -30,7 +35,7 @@ impl Polynomial {
         // Assign the constant-term (`intercept`) to the provided input.
         coefficients[0] = intercept;
         // Randomly generate the remaining coefficients.
-        rand::thread_rng().fill(&mut coefficients[1..]);
+        rng.fill(&mut coefficients[1..]);
 
         Polynomial { coefficients }
     }
@@ -45,7 +50,11 @@ impl Polynomial {
     ///
     /// ## Panics
     /// * If `x` is zero, since the evaluation at `x = 0` is not allowed.
-    /// This is a safeguard to prevent revealing the secret byte set as the constant term.
+    ///   This is a safeguard to prevent revealing the secret byte set as the constant term.
+    ///
+    /// ## Notes
+    /// * Shares are evaluated with [`Polynomial::evaluate_batch`]; this is its reference in tests.
+    #[cfg(test)]
     pub(crate) fn evaluate(&self, x: u8) -> u8 {
         // Mathematically, evaluating a polynomial at `x = 0` is valid and results to the constant term (`self.coefficients[0]`).
         // However, that's not allowed in order to prevent revealing the secret byte, which in this case is the constant term.
@@ -83,31 +92,231 @@ impl Polynomial {
     ///
     /// ## Notes
     /// * This function assumes that `x_samples` and `y_samples` have the same length and contain no duplicate x-values.
-    /// The caller must ensure this for performance reasons in order to avoid reduntant checks when iterating.
+    ///   The caller must ensure this for performance reasons in order to avoid reduntant checks when iterating.
+    /// * Shares are combined with [`Polynomial::interpolate_batch`]; this is its reference in tests.
+    #[cfg(test)]
     pub(crate) fn interpolate(x_samples: &[u8], y_samples: &[u8], x: u8) -> u8 {
         let limit = x_samples.len();
         let mut result = 0;
         // Iterate over each sample to construct the Lagrange basis polynomial.
-        for i in 0..limit {
-            let mut basis = 1;
-            // Construct the basis polynomial for each i-th term.
-            for j in 0..limit {
-                if i == j {
-                    continue;
-                }
-                // Calculate the numerator and denominator for the Lagrange basis.
-                let num = ops::add(x, x_samples[j]);
-                let denom = ops::add(x_samples[i], x_samples[j]);
-                let term = ops::div(num, denom);
-                // Multiply the basis by the current term.
-                basis = ops::mult(basis, term);
-            }
+        for (i, &y) in y_samples.iter().enumerate().take(limit) {
+            let basis = Polynomial::basis(x_samples, i, x);
             // Multiply the y-sample by the basis and add to the result.
-            let group = ops::mult(y_samples[i], basis);
+            let group = ops::mult(y, basis);
             result = ops::add(result, group);
         }
         result
     }
+
+    /// Evaluates many polynomials of the same degree at each of the given points, processing
+    /// all polynomials at once with the fastest [`Backend`] supported by the CPU.
+    ///
+    /// The result is the same as calling [`Polynomial::evaluate`] for every polynomial and point,
+    /// and is computed in constant time with respect to the coefficients.
+    ///
+    /// ## Arguments
+    /// * `polynomials` - The polynomials to evaluate.
+    /// * `x_coordinates` - The points at which to evaluate the polynomials.
+    /// * `ys` - The output, where `ys[p][i]` is set to the value of `polynomials[i]` at `x_coordinates[p]`.
+    ///
+    /// ## Panics
+    /// * If any of `x_coordinates` is zero, as for [`Polynomial::evaluate`].
+    pub(crate) fn evaluate_batch<S: AsMut<[u8]>>(polynomials: &[Polynomial], x_coordinates: &[u8], ys: &mut [S]) {
+        Polynomial::evaluate_batch_with(Backend::detect(), polynomials, x_coordinates, ys)
+    }
+
+    /// Evaluates many polynomials of the same degree at each of the given points with `backend`.
+    ///
+    /// ## Panics
+    /// * The same as [`Polynomial::evaluate_batch`], and if the CPU does not support `backend`.
+    pub(crate) fn evaluate_batch_with<S: AsMut<[u8]>>(
+        backend: Backend,
+        polynomials: &[Polynomial],
+        x_coordinates: &[u8],
+        ys: &mut [S],
+    ) {
+        // The same safe-guard as in `evaluate`, to prevent revealing the secret bytes.
+        if x_coordinates.contains(&0) {
+            panic!("evaluation not allowed for x = 0");
+        }
+        if backend == Backend::Portable {
+            return Polynomial::evaluate_bitsliced(polynomials, x_coordinates, ys);
+        }
+        let Some(count) = polynomials.first().map(|p| p.coefficients.len()) else {
+            return;
+        };
+        let len = polynomials.len();
+
+        // Lay out the coefficients with one row per power, holding that coefficient of every polynomial.
+        let mut rows = Zeroizing::new(vec![0u8; count * len]);
+        for (i, polynomial) in polynomials.iter().enumerate() {
+            for (power, &coefficient) in polynomial.coefficients.iter().enumerate() {
+                rows[power * len + i] = coefficient;
+            }
+        }
+
+        let rows: Vec<&[u8]> = rows.chunks_exact(len).collect();
+        let mut next = Zeroizing::new(vec![0u8; len]);
+        for (&x, y) in x_coordinates.iter().zip(ys.iter_mut()) {
+            Polynomial::horner(&rows, &Multiplier::new(backend, x), &mut y.as_mut()[..len], &mut next);
+        }
+    }
+
+    /// Evaluates polynomials laid out with one row per power, where `rows[power][i]` is that
+    /// coefficient of the `i`-th polynomial, at the point held by `x` using Horner's method.
+    ///
+    /// ## Arguments
+    /// * `rows` - The coefficients, from the intercept up to the highest power, each holding at least `y.len()` values.
+    /// * `x` - The point at which to evaluate the polynomials.
+    /// * `y` - The output, where `y[i]` is set to the value of the `i`-th polynomial.
+    /// * `next` - Scratch space of the same length as `y`, so that no memory is allocated.
+    pub(crate) fn horner<R: AsRef<[u8]>>(rows: &[R], x: &Multiplier, y: &mut [u8], next: &mut [u8]) {
+        let len = y.len();
+        let (highest, rest) = rows.split_last().expect("empty coefficients");
+
+        // Horner's method for polynomial evaluation, for every polynomial at once.
+        y.copy_from_slice(&highest.as_ref()[..len]);
+        for row in rest.iter().rev() {
+            next.copy_from_slice(&row.as_ref()[..len]);
+            x.mul_add(next, y);
+            y.copy_from_slice(next);
+        }
+    }
+
+    /// Computes the values of many polynomials at a given point `x` using Lagrange interpolation,
+    /// processing all polynomials at once with the fastest [`Backend`] supported by the CPU.
+    ///
+    /// All polynomials share the same x-coordinates, so the Lagrange basis is computed only once.
+    /// The result is the same as calling [`Polynomial::interpolate`] for every polynomial, and is
+    /// computed in constant time with respect to the y-coordinates.
+    ///
+    /// ## Arguments
+    /// * `x_samples` - Array of x-coordinates of the dataset.
+    /// * `y_samples` - The y-coordinates for each of `x_samples`, where `y_samples[i][j]` belongs to the `j`-th polynomial.
+    /// * `x` - The x-coordinate at which the interpolated polynomials are to be computed.
+    /// * `out` - The output, where `out[j]` is set to the value of the `j`-th polynomial at `x`.
+    ///
+    /// ## Notes
+    /// * The same assumptions as for [`Polynomial::interpolate`] apply, and each of `y_samples`
+    ///   must hold at least `out.len()` values.
+    pub(crate) fn interpolate_batch<S: AsRef<[u8]>>(x_samples: &[u8], y_samples: &[S], x: u8, out: &mut [u8]) {
+        Polynomial::interpolate_batch_with(Backend::detect(), x_samples, y_samples, x, out)
+    }
+
+    /// Computes the values of many polynomials at a given point `x` with `backend`.
+    ///
+    /// ## Panics
+    /// * If the CPU does not support `backend`.
+    pub(crate) fn interpolate_batch_with<S: AsRef<[u8]>>(
+        backend: Backend,
+        x_samples: &[u8],
+        y_samples: &[S],
+        x: u8,
+        out: &mut [u8],
+    ) {
+        let weights = Polynomial::weights(x_samples, x);
+        Polynomial::weighted_sum_with(backend, &weights, y_samples, out)
+    }
+
+    /// Computes the value of every Lagrange basis polynomial of `x_samples` at `x`.
+    ///
+    /// The value of any polynomial through the samples at `x` is the sum of its y-coordinates
+    /// multiplied by these weights, which only depend on the x-coordinates.
+    ///
+    /// ## Notes
+    /// * The same assumptions as for [`Polynomial::interpolate`] apply.
+    pub(crate) fn weights(x_samples: &[u8], x: u8) -> Vec<u8> {
+        (0..x_samples.len()).map(|i| Polynomial::basis(x_samples, i, x)).collect()
+    }
+
+    /// Computes the weighted sums `out[j] = Σ weights[i] * y_samples[i][j]` with the fastest
+    /// [`Backend`] supported by the CPU, in constant time with respect to the y-coordinates.
+    ///
+    /// ## Notes
+    /// * Each of `y_samples` must hold at least `out.len()` values.
+    pub(crate) fn weighted_sum<S: AsRef<[u8]>>(weights: &[u8], y_samples: &[S], out: &mut [u8]) {
+        Polynomial::weighted_sum_with(Backend::detect(), weights, y_samples, out)
+    }
+
+    /// Computes the weighted sums of the y-coordinates with `backend`.
+    ///
+    /// ## Panics
+    /// * If the CPU does not support `backend`.
+    pub(crate) fn weighted_sum_with<S: AsRef<[u8]>>(backend: Backend, weights: &[u8], y_samples: &[S], out: &mut [u8]) {
+        if backend == Backend::Portable {
+            return Polynomial::weighted_sum_bitsliced(weights, y_samples, out);
+        }
+
+        out.fill(0);
+        for (&weight, y) in weights.iter().zip(y_samples) {
+            // Multiply the y-samples by the weight and add to the result.
+            Multiplier::new(backend, weight).mul_add(out, &y.as_ref()[..out.len()]);
+        }
+    }
+
+    /// Evaluates many polynomials at each of the given points, 64 polynomials at a time
+    /// using bitsliced arithmetic, which stays in bitsliced form across Horner's method.
+    fn evaluate_bitsliced<S: AsMut<[u8]>>(polynomials: &[Polynomial], x_coordinates: &[u8], ys: &mut [S]) {
+        let mut bytes = Zeroizing::new([0u8; LANES]);
+        for (chunk_idx, chunk) in polynomials.chunks(LANES).enumerate() {
+            let offset = chunk_idx * LANES;
+
+            // Transpose the chunk into one bitsliced value per power, so that each lane holds one polynomial.
+            let coefficients: Vec<Bitsliced> = (0..chunk[0].coefficients.len())
+                .map(|power| {
+                    for (byte, polynomial) in bytes.iter_mut().zip(chunk) {
+                        *byte = polynomial.coefficients[power];
+                    }
+                    Bitsliced::load(&bytes[..chunk.len()])
+                })
+                .collect();
+
+            for (&x, y) in x_coordinates.iter().zip(ys.iter_mut()) {
+                let x = Bitsliced::broadcast(x);
+                // Horner's method for polynomial evaluation, in every lane at once.
+                let mut result = coefficients.last().cloned().expect("empty coefficients");
+                for coefficient in coefficients.iter().rev().skip(1) {
+                    result = result.mult(&x).add(coefficient);
+                }
+                result.store(&mut y.as_mut()[offset..offset + chunk.len()]);
+            }
+        }
+    }
+
+    /// Computes the weighted sums of the y-coordinates, 64 at a time using bitsliced arithmetic,
+    /// which stays in bitsliced form across the sum.
+    fn weighted_sum_bitsliced<S: AsRef<[u8]>>(weights: &[u8], y_samples: &[S], out: &mut [u8]) {
+        let weights: Vec<Bitsliced> = weights.iter().map(|&weight| Bitsliced::broadcast(weight)).collect();
+
+        for (chunk_idx, chunk) in out.chunks_mut(LANES).enumerate() {
+            let offset = chunk_idx * LANES;
+            let mut result = Bitsliced::default();
+            for (weight, y) in weights.iter().zip(y_samples) {
+                // Multiply the y-samples by the weight and add to the result.
+                let y = Bitsliced::load(&y.as_ref()[offset..offset + chunk.len()]);
+                result = result.add(&y.mult(weight));
+            }
+            result.store(chunk);
+        }
+    }
+
+    /// Computes the value of the `i`-th Lagrange basis polynomial of `x_samples` at `x`.
+    pub(crate) fn basis(x_samples: &[u8], i: usize, x: u8) -> u8 {
+        let mut basis = 1;
+        // Construct the basis polynomial for the i-th term.
+        for j in 0..x_samples.len() {
+            if i == j {
+                continue;
+            }
+            // Calculate the numerator and denominator for the Lagrange basis.
+            let num = ops::add(x, x_samples[j]);
+            let denom = ops::add(x_samples[i], x_samples[j]);
+            let term = ops::div(num, denom);
+            // Multiply the basis by the current term.
+            basis = ops::mult(basis, term);
+        }
+        basis
+    }
 }
 
 // This is important for security purposes to prevent sensitive data
@@ -123,13 +332,16 @@ impl Drop for Polynomial {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::error::Error;
+    use alloc::boxed::Box;
+    use core::error::Error;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
     // Polynomial generation with random coefficients.
     #[test]
     fn it_generates() -> Result<(), Box<dyn Error>> {
         let degree = 3;
         let intercept = 5;
-        let polynomial = Polynomial::generate(intercept, degree);
+        let polynomial = Polynomial::generate(intercept, degree, &mut ChaCha20Rng::from_seed([1; 32]));
 
         // The first coefficient is the intercept
         assert_eq!(polynomial.coefficients[0], intercept);
@@ -176,4 +388,50 @@ mod tests {
 
         polynomial.evaluate(x);
     }
+
+    // Batch evaluation agrees with evaluating each polynomial, across several chunks.
+    #[test]
+    fn it_evaluates_batch() {
+        let mut rng = ChaCha20Rng::from_seed([2; 32]);
+        let polynomials: Vec<_> = (0..150).map(|i| Polynomial::generate(i as u8, 4, &mut rng)).collect();
+        let x_coordinates = [0x01, 0x2A, 0xFF];
+
+        for backend in Backend::available() {
+            let mut ys = vec![vec![0u8; polynomials.len()]; x_coordinates.len()];
+            Polynomial::evaluate_batch_with(backend, &polynomials, &x_coordinates, &mut ys);
+            for (&x, y) in x_coordinates.iter().zip(&ys) {
+                let expected: Vec<u8> = polynomials.iter().map(|p| p.evaluate(x)).collect();
+                assert_eq!(*y, expected, "{:?}", backend);
+            }
+        }
+    }
+
+    // Batch evaluation refuses `x = 0` like the scalar evaluation.
+    #[test]
+    #[should_panic(expected = "evaluation not allowed for x = 0")]
+    fn it_fails_to_evaluate_batch_zero() {
+        let polynomials = vec![Polynomial { coefficients: vec![0x7C, 0x3E] }];
+        Polynomial::evaluate_batch(&polynomials, &[0x01, 0x00], &mut [[0u8; 1]; 2]);
+    }
+
+    // Batch interpolation agrees with interpolating each polynomial, across several chunks.
+    #[test]
+    fn it_interpolates_batch() {
+        let x_samples = [0x3D, 0xA7, 0x1E];
+        let y_samples: Vec<Vec<u8>> = (0..3).map(|i| (0..100).map(|j| (j * 7 + i * 31) as u8).collect()).collect();
+
+        for backend in Backend::available() {
+            for x in [0x00, 0x5A] {
+                let mut out = vec![0u8; 100];
+                Polynomial::interpolate_batch_with(backend, &x_samples, &y_samples, x, &mut out);
+                for (j, &value) in out.iter().enumerate() {
+                    let ys: Vec<u8> = y_samples.iter().map(|y| y[j]).collect();
+                    assert_eq!(value, Polynomial::interpolate(&x_samples, &ys, x), "{:?}", backend);
+                }
+            }
+        }
+        let mut out = [0u8; 1];
+        Polynomial::interpolate_batch(&x_samples, &[[0x1A], [0x2B], [0x4C]], 0x5A, &mut out);
+        assert_eq!(out, [0xCE]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,167 @@
+use alloc::{vec, vec::Vec};
+
+use crate::combine::{x_samples, CombineError, CombineSecret};
+use crate::polynomial::Polynomial;
+
+/// Reconstructs secrets from shares at a fixed set of x-coordinates.
+///
+/// The Lagrange basis values at `x = 0` depend only on the x-coordinates, so they are
+/// computed once when the reconstructor is created. Each byte of a secret is then a dot
+/// product of these weights with the y-values of the shares, which makes the reconstructor
+/// worthwhile for many secrets split to the same holders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconstructor {
+    /// The x-coordinates of the shares, in the order given.
+    xs: Vec<u8>,
+    /// The Lagrange basis value at `x = 0` for each of `xs`.
+    weights: Vec<u8>,
+}
+
+impl Reconstructor {
+    /// Creates a reconstructor for shares at the given x-coordinates.
+    ///
+    /// ## Arguments
+    /// * `xs` - The unique x-coordinates of the shares to combine.
+    ///
+    /// ## Errors
+    /// * [`CombineError::TooFewShares`] if fewer than two x-coordinates are given.
+    /// * [`CombineError::DuplicateX`] if an x-coordinate is repeated.
+    pub fn new(xs: &[u8]) -> Result<Reconstructor, CombineError> {
+        if xs.len() < 2 {
+            return Err(CombineError::TooFewShares { needed: 2, found: xs.len() });
+        }
+
+        // Ensure that the x-coordinates are unique, remembering where
+        // each one was first seen so a duplicate can be reported precisely.
+        let mut seen = [None; 256];
+        for (idx, &x) in xs.iter().enumerate() {
+            if let Some(first) = seen[x as usize] {
+                return Err(CombineError::DuplicateX { x, first, second: idx });
+            }
+            seen[x as usize] = Some(idx);
+        }
+
+        Ok(Reconstructor::from_x_samples(xs.to_vec()))
+    }
+
+    /// Creates a reconstructor for the x-coordinates of shares that were already validated.
+    ///
+    /// ## Notes
+    /// * This function assumes that `x_samples` holds at least two unique x-coordinates,
+    ///   as returned by [`x_samples`].
+    pub(crate) fn from_x_samples(x_samples: Vec<u8>) -> Reconstructor {
+        let weights = Polynomial::weights(&x_samples, 0);
+        Reconstructor { xs: x_samples, weights }
+    }
+
+    /// Returns the x-coordinates of the shares, in the order given.
+    pub fn xs(&self) -> &[u8] {
+        &self.xs
+    }
+
+    /// Returns the Lagrange basis value at `x = 0` for each of the x-coordinates.
+    #[cfg(feature = "std")]
+    pub(crate) fn weights(&self) -> &[u8] {
+        &self.weights
+    }
+
+    /// Combines shares at the x-coordinates of the reconstructor to reconstruct the secret.
+    ///
+    /// The shares may be given in any order.
+    ///
+    /// ## Arguments
+    /// * `shares` - Shares of the secret, either as [`Share`](crate::Share)s or raw bytes.
+    ///
+    /// ## Returns
+    /// * The original secret if successful; otherwise, an error.
+    ///
+    /// ## Errors
+    /// * [`CombineError::UnexpectedX`] if a share is not at one of the x-coordinates.
+    /// * [`CombineError::TooFewShares`] if a share is missing for any of the x-coordinates.
+    /// * The same as [`combine`](crate::combine) for inconsistent shares.
+    pub fn combine<T: CombineSecret>(&self, shares: T) -> Result<Vec<u8>, CombineError> {
+        // Validate the shares and gather their x-coordinates.
+        let x_samples = x_samples(&shares)?;
+
+        // Look up the weight of each share, which also rejects shares at other x-coordinates.
+        let weights = x_samples
+            .iter()
+            .enumerate()
+            .map(|(index, &x)| {
+                let position = self.xs.iter().position(|&other| other == x);
+                position.map(|p| self.weights[p]).ok_or(CombineError::UnexpectedX { index, x })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if weights.len() < self.xs.len() {
+            return Err(CombineError::TooFewShares { needed: self.xs.len(), found: weights.len() });
+        }
+
+        Ok(Reconstructor::weighted_sum(&weights, &shares))
+    }
+
+    /// Combines shares that were already validated, without checking them again.
+    ///
+    /// ## Notes
+    /// * This function assumes that `shares` are consistent and given in the order of the
+    ///   x-coordinates of the reconstructor, as when it was created by [`Reconstructor::from_x_samples`].
+    pub(crate) fn combine_validated<T: CombineSecret>(&self, shares: &T) -> Vec<u8> {
+        Reconstructor::weighted_sum(&self.weights, shares)
+    }
+
+    /// Computes each byte of the secret as the dot product of `weights` and the y-values of `shares`.
+    fn weighted_sum<T: CombineSecret>(weights: &[u8], shares: &T) -> Vec<u8> {
+        let first_part_len = shares.get(0).as_ref().len();
+        let mut secret = vec![0u8; first_part_len - 1];
+        let y_samples: Vec<&[u8]> = shares.iter().map(AsRef::as_ref).collect();
+        Polynomial::weighted_sum(weights, &y_samples, &mut secret);
+        secret
+    }
+}
+
+// Test cases for the `Reconstructor`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combine, split_at_with_rng, split_with_rng, Share};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // A reconstructor is reused for many secrets split to the same x-coordinates.
+    #[test]
+    fn it_combines_many_secrets() {
+        let mut rng = ChaCha20Rng::from_seed([1; 32]);
+        let xs = [7, 1, 255];
+        let reconstructor = Reconstructor::new(&xs).expect("invalid x-coordinates");
+        assert_eq!(reconstructor.xs(), xs);
+
+        for secret in [&b"first"[..], b"second secret", &[0xAB; 200]] {
+            let shares = split_at_with_rng(secret, &[1, 7, 42, 255], 3, &mut rng).expect("split failed");
+            let selected = [shares[3].clone(), shares[0].clone(), shares[1].clone()];
+            assert_eq!(reconstructor.combine(&selected[..]).expect("combine failed"), secret);
+            assert_eq!(combine(&selected[..]).expect("combine failed"), secret);
+        }
+    }
+
+    // Shares at other x-coordinates, or too few shares, are rejected.
+    #[test]
+    fn it_fails_with_other_shares() {
+        let mut rng = ChaCha20Rng::from_seed([2; 32]);
+        let shares = split_at_with_rng(b"secret", &[1, 2, 3, 4], 2, &mut rng).expect("split failed");
+        let reconstructor = Reconstructor::new(&[1, 2, 3]).expect("invalid x-coordinates");
+
+        assert_eq!(reconstructor.combine(&shares[1..]), Err(CombineError::UnexpectedX { index: 2, x: 4 }));
+        assert_eq!(reconstructor.combine(&shares[..2]), Err(CombineError::TooFewShares { needed: 3, found: 2 }));
+        assert_eq!(reconstructor.combine(&shares[..3]).expect("combine failed"), b"secret");
+
+        let random: Vec<Share> = split_with_rng(b"secret", 3, 2, &mut rng).expect("split failed");
+        let xs: Vec<u8> = random.iter().map(Share::x).collect();
+        assert_eq!(Reconstructor::new(&xs).expect("invalid x-coordinates").combine(&random).expect("combine failed"), b"secret");
+    }
+
+    // The x-coordinates of a reconstructor must be unique and at least two.
+    #[test]
+    fn it_fails_with_invalid_x_coordinates() {
+        assert_eq!(Reconstructor::new(&[9]), Err(CombineError::TooFewShares { needed: 2, found: 1 }));
+        assert_eq!(Reconstructor::new(&[9, 3, 9]), Err(CombineError::DuplicateX { x: 9, first: 0, second: 2 }));
+    }
+}
@@ -0,0 +1,255 @@
+use alloc::{vec, vec::Vec};
+
+use crate::combine::{x_samples, CombineError, CombineSecret};
+use crate::ops;
+
+/// Combines shares to reconstruct the secret, correcting corrupted shares.
+///
+/// The shares of each byte of the secret form a Reed–Solomon codeword, so with `n` shares
+/// and a threshold of `k`, up to `(n - k) / 2` corrupted or malicious shares can be
+/// identified and excluded. Each byte is decoded independently with the Berlekamp–Welch
+/// algorithm, and a share is reported as corrupted if any of its y-values is wrong.
+///
+/// Decoding solves a linear system whose pivots depend on the y-values, so unlike
+/// [`combine`](crate::combine) this function does not run in constant time. It also costs
+/// `O(n^3)` field operations per byte of the secret.
+///
+/// ## Arguments
+/// * `shares` - Shares of the secret, either as [`Share`](crate::Share)s or raw bytes.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * The original secret, together with the x-coordinates of the shares found to be corrupted.
+///
+/// ## Errors
+/// * [`CombineError::ThresholdOutOfRange`] if `threshold` is not within `2..=255`.
+/// * [`CombineError::TooFewShares`] if fewer than `threshold` shares are provided.
+/// * [`CombineError::Uncorrectable`] if more than `(n - k) / 2` shares are corrupted.
+/// * The same as [`combine`](crate::combine) for inconsistent shares.
+pub fn combine_robust<T: CombineSecret>(shares: T, threshold: usize) -> Result<(Vec<u8>, Vec<u8>), CombineError> {
+    if !(2..=255).contains(&threshold) {
+        return Err(CombineError::ThresholdOutOfRange { threshold });
+    }
+
+    // Validate the shares and gather their x-coordinates.
+    let x_samples = x_samples(&shares)?;
+    let parts = x_samples.len();
+    if parts < threshold {
+        return Err(CombineError::TooFewShares { needed: threshold, found: parts });
+    }
+
+    // The number of errors that can be corrected with the surplus shares.
+    let correctable = (parts - threshold) / 2;
+
+    let secret_len = shares.get(0).as_ref().len() - 1;
+    let mut secret = vec![0u8; secret_len];
+    let mut y_samples = vec![0u8; parts];
+    let mut corrupted = vec![false; parts];
+
+    for idx in 0..secret_len {
+        for (i, part) in shares.iter().map(AsRef::as_ref).enumerate() {
+            y_samples[i] = part[idx];
+        }
+
+        let polynomial = decode(&x_samples, &y_samples, threshold, correctable)
+            .ok_or(CombineError::Uncorrectable { correctable })?;
+
+        // The intercept of the decoded polynomial is the byte of the secret.
+        secret[idx] = polynomial[0];
+
+        // Every share that disagrees with the decoded polynomial is corrupted.
+        for i in 0..parts {
+            if evaluate(&polynomial, x_samples[i]) != y_samples[i] {
+                corrupted[i] = true;
+            }
+        }
+    }
+
+    // Each byte may have been decoded with different shares in error, but together
+    // they must not exceed the number of errors that can be corrected.
+    if corrupted.iter().filter(|&&c| c).count() > correctable {
+        return Err(CombineError::Uncorrectable { correctable });
+    }
+
+    let corrupted = x_samples.iter().zip(&corrupted).filter(|(_, &c)| c).map(|(&x, _)| x).collect();
+    Ok((secret, corrupted))
+}
+
+/// Decodes the polynomial of degree below `k` through the points, with up to `e` of them in error.
+///
+/// Berlekamp–Welch looks for an error locator `E(x)`, monic of degree `e`, and `Q(x) = P(x) * E(x)`
+/// of degree below `e + k`, such that `Q(x_i) = y_i * E(x_i)` holds for every point. The roots of
+/// `E(x)` include the x-coordinates of the erroneous points, so `P(x) = Q(x) / E(x)`.
+///
+/// ## Returns
+/// * The `k` coefficients of `P(x)`, ordered from the intercept, or `None` if more than `e` points are in error.
+fn decode(x_samples: &[u8], y_samples: &[u8], k: usize, e: usize) -> Option<Vec<u8>> {
+    // The unknowns are the coefficients of `Q(x)`, followed by all but the leading coefficient of `E(x)`.
+    let unknowns = 2 * e + k;
+
+    // Build the augmented matrix with one equation per point:
+    // `sum(Q_j * x^j) + y * sum(E_j * x^j) = y * x^e`, as subtraction is addition in `GF(2^8)`.
+    let mut matrix: Vec<Vec<u8>> = x_samples
+        .iter()
+        .zip(y_samples)
+        .map(|(&x, &y)| {
+            let mut row = vec![0u8; unknowns + 1];
+            let mut power = 1;
+            for j in 0..(e + k) {
+                row[j] = power;
+                if j < e {
+                    row[e + k + j] = ops::mult(y, power);
+                }
+                if j == e {
+                    row[unknowns] = ops::mult(y, power);
+                }
+                power = ops::mult(power, x);
+            }
+            // With `k >= 1`, the power `x^e` has always been reached above.
+            row
+        })
+        .collect();
+
+    let solution = solve(&mut matrix, unknowns)?;
+
+    let product = &solution[..e + k];
+    let mut locator = solution[e + k..].to_vec();
+    locator.push(1);
+
+    // Divide `Q(x)` by the monic `E(x)` using long division.
+    let mut remainder = product.to_vec();
+    let mut polynomial = vec![0u8; k];
+    for i in (0..k).rev() {
+        let coefficient = remainder[i + e];
+        polynomial[i] = coefficient;
+        for (j, &l) in locator.iter().enumerate() {
+            remainder[i + j] = ops::add(remainder[i + j], ops::mult(coefficient, l));
+        }
+    }
+
+    // A non-zero remainder means `E(x)` does not divide `Q(x)`, i.e. there were too many errors.
+    if remainder.iter().any(|&r| r != 0) {
+        return None;
+    }
+
+    // Too many points disagreeing with `P(x)` means it is not the polynomial that was shared.
+    let errors = x_samples.iter().zip(y_samples).filter(|&(&x, &y)| evaluate(&polynomial, x) != y).count();
+    if errors > e {
+        return None;
+    }
+
+    Some(polynomial)
+}
+
+/// Solves a linear system given as an augmented matrix using Gauss–Jordan elimination.
+///
+/// Free variables are set to zero, since any solution of the Berlekamp–Welch system leads to the same polynomial.
+///
+/// ## Returns
+/// * The values of the `unknowns`, or `None` if the system is inconsistent.
+fn solve(matrix: &mut [Vec<u8>], unknowns: usize) -> Option<Vec<u8>> {
+    let mut pivots = Vec::with_capacity(unknowns);
+    let mut row = 0;
+
+    for col in 0..unknowns {
+        // Find a row with a non-zero entry in the current column.
+        let Some(pivot) = (row..matrix.len()).find(|&r| matrix[r][col] != 0) else {
+            continue;
+        };
+        matrix.swap(row, pivot);
+
+        // Normalize the pivot row, so that the pivot becomes one.
+        let scale = ops::div(1, matrix[row][col]);
+        for value in matrix[row].iter_mut() {
+            *value = ops::mult(*value, scale);
+        }
+
+        // Eliminate the current column from every other row.
+        let pivot_row = matrix[row].clone();
+        for (r, other) in matrix.iter_mut().enumerate() {
+            let factor = other[col];
+            if r == row || factor == 0 {
+                continue;
+            }
+            for (value, &p) in other[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value = ops::add(*value, ops::mult(factor, p));
+            }
+        }
+
+        pivots.push(col);
+        row += 1;
+    }
+
+    // Any remaining row with a non-zero right-hand side is a contradiction.
+    if matrix[row..].iter().any(|r| r[unknowns] != 0) {
+        return None;
+    }
+
+    let mut solution = vec![0u8; unknowns];
+    for (r, &col) in pivots.iter().enumerate() {
+        solution[col] = matrix[r][unknowns];
+    }
+    Some(solution)
+}
+
+/// Evaluates a polynomial, given by its coefficients ordered from the intercept, using Horner's method.
+fn evaluate(coefficients: &[u8], x: u8) -> u8 {
+    coefficients.iter().rev().fold(0, |result, &coefficient| ops::add(ops::mult(result, x), coefficient))
+}
+
+// Test cases for the robust reconstruction.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{split_deterministic, Share};
+
+    // Splits a fixed secret and returns the shares as bytes.
+    fn shares(parts: usize, threshold: usize) -> Vec<Vec<u8>> {
+        split_deterministic(b"test_secret", parts, threshold, &[3; 32])
+            .expect("split failed")
+            .iter()
+            .map(Share::to_bytes)
+            .collect()
+    }
+
+    // Shares without corruption are reconstructed as is.
+    #[test]
+    fn it_combines_without_errors() {
+        let shares = shares(5, 3);
+        assert_eq!(combine_robust(&shares, 3), Ok((b"test_secret".to_vec(), vec![])));
+        assert_eq!(combine_robust(&shares[..3], 3), Ok((b"test_secret".to_vec(), vec![])));
+    }
+
+    // Up to `(n - k) / 2` corrupted shares are corrected and reported.
+    #[test]
+    fn it_corrects_errors() {
+        let mut shares = shares(7, 3);
+        let (first, second) = (shares[1][11], shares[4][11]);
+
+        // Corrupt a single byte of one share and every byte of another.
+        shares[1][3] ^= 0x5A;
+        for y in shares[4].iter_mut().take(11) {
+            *y = y.wrapping_add(1);
+        }
+
+        assert_eq!(combine_robust(&shares, 3), Ok((b"test_secret".to_vec(), vec![first, second])));
+    }
+
+    // More corrupted shares than can be corrected are detected.
+    #[test]
+    fn it_fails_with_too_many_errors() {
+        let mut shares = shares(6, 3);
+        shares[0][0] ^= 0x01;
+        shares[2][0] ^= 0x02;
+
+        assert_eq!(combine_robust(&shares, 3), Err(CombineError::Uncorrectable { correctable: 1 }));
+    }
+
+    // Invalid parameters are rejected before decoding.
+    #[test]
+    fn it_fails_with_invalid_parameters() {
+        let shares = shares(5, 3);
+        assert_eq!(combine_robust(&shares, 1), Err(CombineError::ThresholdOutOfRange { threshold: 1 }));
+        assert_eq!(combine_robust(&shares[..2], 3), Err(CombineError::TooFewShares { needed: 3, found: 2 }));
+    }
+}
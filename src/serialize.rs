@@ -0,0 +1,244 @@
+use alloc::vec::Vec;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use zeroize::Zeroizing;
+
+use crate::encoding::Encoding;
+use crate::header::HEADER_VERSION;
+use crate::params::SplitParameters;
+use crate::share::Share;
+use crate::split::{check_parameters, check_x_coordinates};
+
+// Shares are serialized as unpadded base64url strings in human-readable formats, and as raw bytes otherwise.
+impl Serialize for Share {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let text = Zeroizing::new(self.encode(Encoding::Base64Url));
+            serializer.serialize_str(&text)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Share {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ShareVisitor)
+        } else {
+            deserializer.deserialize_bytes(ShareVisitor)
+        }
+    }
+}
+
+/// Visits a share in either of its serialized forms.
+struct ShareVisitor;
+
+impl<'de> Visitor<'de> for ShareVisitor {
+    type Value = Share;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "a share as a base64url string or as bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Share, E> {
+        Share::decode(v, Encoding::Base64Url).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Share, E> {
+        Share::from_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Share, E> {
+        Share::try_from(v).map_err(E::custom)
+    }
+
+    // Formats without a bytes type fall back to a sequence of integers.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Share, A::Error> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(seq.size_hint().unwrap_or(0)));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Share::from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+// The x-coordinates are serialized like the bytes of a share.
+impl Serialize for SplitParameters {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("SplitParameters", 4)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("threshold", &self.threshold)?;
+        state.serialize_field("parts", &self.parts)?;
+        state.serialize_field("xs", &XCoordinates(self.xs.clone()))?;
+        state.end()
+    }
+}
+
+// Parameters that no split could have produced are rejected.
+impl<'de> Deserialize<'de> for SplitParameters {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawParameters { version, threshold, parts, xs: XCoordinates(xs) } =
+            RawParameters::deserialize(deserializer)?;
+
+        if version != HEADER_VERSION {
+            return Err(de::Error::custom(format_args!("unsupported version {}", version)));
+        }
+        if parts != xs.len() {
+            return Err(de::Error::custom(format_args!("{} parts with {} x-coordinates", parts, xs.len())));
+        }
+        check_parameters(parts, threshold, false).map_err(de::Error::custom)?;
+        check_x_coordinates(&xs).map_err(de::Error::custom)?;
+
+        Ok(SplitParameters { version, threshold, parts, xs })
+    }
+}
+
+/// The fields of serialized [`SplitParameters`], before they are validated.
+#[derive(serde::Deserialize)]
+#[serde(rename = "SplitParameters")]
+struct RawParameters {
+    version: u8,
+    threshold: usize,
+    parts: usize,
+    xs: XCoordinates,
+}
+
+/// The x-coordinates of a split, as an unpadded base64url string in human-readable formats, and as raw bytes otherwise.
+struct XCoordinates(Vec<u8>);
+
+impl Serialize for XCoordinates {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&Encoding::Base64Url.encode(&self.0))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for XCoordinates {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(XCoordinatesVisitor)
+        } else {
+            deserializer.deserialize_bytes(XCoordinatesVisitor)
+        }
+    }
+}
+
+/// Visits x-coordinates in either of their serialized forms.
+struct XCoordinatesVisitor;
+
+impl<'de> Visitor<'de> for XCoordinatesVisitor {
+    type Value = XCoordinates;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "x-coordinates as a base64url string or as bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<XCoordinates, E> {
+        Encoding::Base64Url.decode(v).map(XCoordinates).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<XCoordinates, E> {
+        Ok(XCoordinates(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<XCoordinates, E> {
+        Ok(XCoordinates(v))
+    }
+
+    // Formats without a bytes type fall back to a sequence of integers.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<XCoordinates, A::Error> {
+        let mut xs = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(x) = seq.next_element()? {
+            xs.push(x);
+        }
+        Ok(XCoordinates(xs))
+    }
+}
+
+// Test cases for the `serde` support.
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use crate::{split_deterministic, Share, SplitParameters};
+
+    // Shares are strings in JSON.
+    #[test]
+    fn it_serializes_shares_to_json() {
+        let share = Share::new(0x2A, &[0x00, 0x1F, 0x80, 0xFF]).expect("invalid share");
+        let json = serde_json::to_string(&share).expect("serialize failed");
+        assert_eq!(json, "\"AB-A_yo\"");
+        assert_eq!(serde_json::from_str::<Share>(&json).expect("deserialize failed"), share);
+
+        assert!(serde_json::from_str::<Share>("\"AB-A_wA\"").is_err());
+        assert!(serde_json::from_str::<Share>("[1, 2]").is_err());
+    }
+
+    // Shares are byte strings in CBOR.
+    #[test]
+    fn it_serializes_shares_to_cbor() {
+        let share = Share::new(0x2A, &[0x00, 0x1F, 0x80, 0xFF]).expect("invalid share");
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&share, &mut cbor).expect("serialize failed");
+        // A byte string of five bytes, followed by the bytes of the share.
+        assert_eq!(cbor, [0x45, 0x00, 0x1F, 0x80, 0xFF, 0x2A]);
+        assert_eq!(ciborium::from_reader::<Share, _>(&cbor[..]).expect("deserialize failed"), share);
+    }
+
+    // The parameters of a split round-trip alongside its shares.
+    #[test]
+    fn it_serializes_parameters() {
+        let shares = split_deterministic(b"secret", 3, 2, &[9; 32]).expect("split failed");
+        let parameters = SplitParameters::from_shares(2, &shares);
+        assert_eq!(parameters.parts, 3);
+        assert_eq!(parameters.xs, shares.iter().map(Share::x).collect::<Vec<_>>());
+
+        let json = serde_json::to_string(&(&parameters, &shares)).expect("serialize failed");
+        let (decoded, decoded_shares): (SplitParameters, Vec<Share>) =
+            serde_json::from_str(&json).expect("deserialize failed");
+        assert_eq!((decoded, decoded_shares), (parameters.clone(), shares.clone()));
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&(&parameters, &shares), &mut cbor).expect("serialize failed");
+        let decoded: (SplitParameters, Vec<Share>) = ciborium::from_reader(&cbor[..]).expect("deserialize failed");
+        assert_eq!(decoded, (parameters, shares));
+    }
+
+    // The x-coordinates are a string in JSON and a byte string in CBOR.
+    #[test]
+    fn it_serializes_xs_compactly() {
+        let parameters = SplitParameters::new(2, vec![1, 2, 3]);
+
+        let json = serde_json::to_value(&parameters).expect("serialize failed");
+        assert_eq!(json["xs"], serde_json::json!("AQID"));
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&parameters, &mut cbor).expect("serialize failed");
+        let value: ciborium::Value = ciborium::from_reader(&cbor[..]).expect("deserialize failed");
+        let xs = value.as_map().expect("not a map").iter().find(|(k, _)| k.as_text() == Some("xs")).map(|(_, v)| v);
+        assert_eq!(xs, Some(&ciborium::Value::Bytes(vec![1, 2, 3])));
+    }
+
+    // Parameters that no split could have produced are rejected.
+    #[test]
+    fn it_rejects_invalid_parameters() {
+        for json in [
+            r#"{"version":2,"threshold":2,"parts":3,"xs":"AQID"}"#,
+            r#"{"version":1,"threshold":2,"parts":4,"xs":"AQID"}"#,
+            r#"{"version":1,"threshold":4,"parts":3,"xs":"AQID"}"#,
+            r#"{"version":1,"threshold":1,"parts":3,"xs":"AQID"}"#,
+            r#"{"version":1,"threshold":2,"parts":3,"xs":"AQAD"}"#,
+            r#"{"version":1,"threshold":2,"parts":3,"xs":"AQIB"}"#,
+        ] {
+            assert!(serde_json::from_str::<SplitParameters>(json).is_err(), "{} was accepted", json);
+        }
+        let valid = r#"{"version":1,"threshold":2,"parts":3,"xs":"AQID"}"#;
+        let decoded: SplitParameters = serde_json::from_str(valid).expect("deserialize failed");
+        assert_eq!(decoded, SplitParameters::new(2, vec![1, 2, 3]));
+    }
+}
@@ -0,0 +1,216 @@
+use alloc::vec::Vec;
+
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// A single share of a split secret.
+///
+/// Each share holds one y-value per byte of the secret, all evaluated at the same x-coordinate.
+/// Its byte layout is the y-values followed by a single trailing byte for the x-coordinate,
+/// which is the layout produced by earlier versions of [`split`](crate::split).
+pub struct Share {
+    /// The y-values followed by the x-coordinate.
+    bytes: Vec<u8>,
+}
+
+/// An error returned when bytes cannot be interpreted as a [`Share`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareError {
+    /// The bytes are too short to hold at least one y-value and the x-coordinate.
+    TooShort {
+        /// The length of the bytes.
+        length: usize,
+    },
+    /// The x-coordinate is zero, which would make the y-values equal to the secret itself.
+    ZeroX,
+}
+
+impl core::fmt::Display for ShareError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ShareError::TooShort { length } => {
+                write!(f, "share is {} bytes long, at least 2 are required", length)
+            }
+            ShareError::ZeroX => write!(f, "share x-coordinate must not be zero"),
+        }
+    }
+}
+
+impl core::error::Error for ShareError {}
+
+impl Share {
+    /// Creates a share from an x-coordinate and its y-values.
+    ///
+    /// ## Arguments
+    /// * `x` - The x-coordinate at which the y-values were evaluated.
+    /// * `y_values` - One y-value per byte of the secret.
+    ///
+    /// ## Errors
+    /// * [`ShareError::ZeroX`] if `x` is zero.
+    /// * [`ShareError::TooShort`] if `y_values` is empty.
+    pub fn new(x: u8, y_values: &[u8]) -> Result<Share, ShareError> {
+        let mut bytes = Vec::with_capacity(y_values.len() + 1);
+        bytes.extend_from_slice(y_values);
+        bytes.push(x);
+        Share::from_vec(bytes)
+    }
+
+    /// Creates a share from bytes laid out as the y-values followed by the x-coordinate.
+    ///
+    /// ## Errors
+    /// * [`ShareError::TooShort`] if `bytes` is shorter than two bytes.
+    /// * [`ShareError::ZeroX`] if the trailing x-coordinate is zero.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Share, ShareError> {
+        Share::from_vec(bytes.to_vec())
+    }
+
+    /// Validates and wraps bytes without copying them.
+    fn from_vec(bytes: Vec<u8>) -> Result<Share, ShareError> {
+        // Wrap the bytes first, so that they are zeroized even if validation fails.
+        let share = Share { bytes };
+        if share.bytes.len() < 2 {
+            return Err(ShareError::TooShort { length: share.bytes.len() });
+        }
+        if share.x() == 0 {
+            return Err(ShareError::ZeroX);
+        }
+        Ok(share)
+    }
+
+    /// Wraps bytes produced within the crate, which are known to be well-formed.
+    pub(crate) fn from_raw(bytes: Vec<u8>) -> Share {
+        debug_assert!(bytes.len() >= 2 && bytes[bytes.len() - 1] != 0);
+        Share { bytes }
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> u8 {
+        self.bytes[self.bytes.len() - 1]
+    }
+
+    /// Returns the y-values of the share, one per byte of the secret.
+    pub fn y_values(&self) -> &[u8] {
+        &self.bytes[..self.bytes.len() - 1]
+    }
+
+    /// Returns the share laid out as the y-values followed by the x-coordinate.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns a copy of the share laid out as the y-values followed by the x-coordinate.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+}
+
+impl AsRef<[u8]> for Share {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Clone for Share {
+    fn clone(&self) -> Self {
+        Share { bytes: self.bytes.clone() }
+    }
+}
+
+impl TryFrom<&[u8]> for Share {
+    type Error = ShareError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Share::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Share {
+    type Error = ShareError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Share::from_vec(bytes)
+    }
+}
+
+impl From<Share> for Vec<u8> {
+    fn from(mut share: Share) -> Self {
+        // Take the bytes out, leaving an empty vector behind to be dropped.
+        core::mem::take(&mut share.bytes)
+    }
+}
+
+// Comparing shares in constant-time avoids leaking, through timing,
+// how many leading y-values two shares have in common.
+impl PartialEq for Share {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes.ct_eq(&other.bytes).into()
+    }
+}
+
+impl Eq for Share {}
+
+// The y-values are deliberately left out, so that shares don't end up in logs.
+impl core::fmt::Debug for Share {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Share")
+            .field("x", &self.x())
+            .field("len", &self.y_values().len())
+            .finish_non_exhaustive()
+    }
+}
+
+// This is important for security purposes to prevent sensitive data
+// from staying in memory after the Share is no longer required and dropped.
+impl Drop for Share {
+    fn drop(&mut self) {
+        // Clear memory associated with the y-values and the x-coordinate.
+        self.bytes.zeroize();
+    }
+}
+
+// Test cases for the `Share` type.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+
+    // Conversions to and from the byte layout.
+    #[test]
+    fn it_converts_bytes() {
+        let share = Share::new(0x2A, &[0x01, 0x02, 0x03]).expect("invalid share");
+        assert_eq!(share.x(), 0x2A);
+        assert_eq!(share.y_values(), &[0x01, 0x02, 0x03]);
+        assert_eq!(share.as_bytes(), &[0x01, 0x02, 0x03, 0x2A]);
+
+        let parsed = Share::try_from(share.as_bytes()).expect("invalid share");
+        assert_eq!(parsed, share);
+
+        let bytes: Vec<u8> = share.into();
+        assert_eq!(bytes, vec![0x01, 0x02, 0x03, 0x2A]);
+    }
+
+    // Bytes that cannot form a share.
+    #[test]
+    fn it_rejects_invalid_bytes() {
+        assert_eq!(Share::from_bytes(&[0x2A]), Err(ShareError::TooShort { length: 1 }));
+        assert_eq!(Share::from_bytes(&[0x01, 0x00]), Err(ShareError::ZeroX));
+        assert_eq!(Share::new(0x01, &[]), Err(ShareError::TooShort { length: 1 }));
+    }
+
+    // Equality compares both the y-values and the x-coordinate.
+    #[test]
+    fn it_compares() {
+        let share = Share::new(0x01, &[0x10, 0x20]).expect("invalid share");
+        assert_eq!(share, Share::new(0x01, &[0x10, 0x20]).expect("invalid share"));
+        assert_ne!(share, Share::new(0x02, &[0x10, 0x20]).expect("invalid share"));
+        assert_ne!(share, Share::new(0x01, &[0x10, 0x21]).expect("invalid share"));
+        assert_ne!(share, Share::new(0x01, &[0x10]).expect("invalid share"));
+    }
+
+    // The debug output must not reveal the y-values.
+    #[test]
+    fn it_redacts_debug() {
+        let share = Share::new(0x07, &[0xAB, 0xCD]).expect("invalid share");
+        assert_eq!(format!("{:?}", share), "Share { x: 7, len: 2, .. }");
+    }
+}
@@ -0,0 +1,796 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use pbkdf2::pbkdf2_hmac;
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use crate::integrity::digest;
+use crate::mnemonic::{parse_words, rs1024_checksum, rs1024_verify, MnemonicError, CHECKSUM_WORDS, RADIX_BITS};
+use crate::polynomial::Polynomial;
+use crate::wordlist::WORDLIST;
+
+/// The customization string of the checksum and the salt prefix of non-extendable shares.
+const CUSTOMIZATION: &[u8] = b"shamir";
+
+/// The customization string of the checksum of extendable shares.
+const CUSTOMIZATION_EXTENDABLE: &[u8] = b"shamir_extendable";
+
+/// The x-coordinate of the digest share.
+const DIGEST_INDEX: u8 = 254;
+
+/// The x-coordinate of the shared secret.
+const SECRET_INDEX: u8 = 255;
+
+/// The length of the digest in the digest share.
+const DIGEST_LEN: usize = 4;
+
+/// The number of rounds of the Feistel network.
+const ROUND_COUNT: u8 = 4;
+
+/// The total number of PBKDF2 iterations for an iteration exponent of zero.
+const BASE_ITERATION_COUNT: u32 = 10000;
+
+/// The minimum length of the master secret, in bytes.
+const MIN_SECRET_LEN: usize = 16;
+
+/// The maximum number of groups, and of members in a group.
+const MAX_SHARE_COUNT: usize = 16;
+
+/// The number of words holding the identifier, the extendable flag and the iteration exponent,
+/// followed by the group and member parameters.
+const HEADER_WORDS: usize = 4;
+
+/// The minimum number of words of a mnemonic, holding a master secret of the minimum length.
+const MIN_MNEMONIC_WORDS: usize = HEADER_WORDS + CHECKSUM_WORDS + (MIN_SECRET_LEN * 8).div_ceil(RADIX_BITS as usize);
+
+/// An error returned when a master secret cannot be split into, or recovered from, SLIP-39 mnemonics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Slip39Error {
+    /// The master secret is shorter than 16 bytes or has an odd length.
+    InvalidSecretLength {
+        /// The length of the master secret.
+        length: usize,
+    },
+    /// The passphrase contains characters other than printable ASCII.
+    InvalidPassphrase,
+    /// The iteration exponent does not fit in four bits.
+    InvalidIterationExponent {
+        /// The requested iteration exponent.
+        exponent: u8,
+    },
+    /// The group threshold is zero or exceeds the number of groups, or there are more than 16 groups.
+    InvalidGroupThreshold {
+        /// The requested group threshold.
+        threshold: usize,
+        /// The requested number of groups.
+        groups: usize,
+    },
+    /// The member threshold of a group is zero or exceeds its number of members, there are more
+    /// than 16 members, or a threshold of one is used with more than one member.
+    InvalidMemberThreshold {
+        /// The position of the group.
+        group: usize,
+        /// The requested member threshold.
+        threshold: usize,
+        /// The requested number of members.
+        members: usize,
+    },
+    /// A mnemonic could not be decoded.
+    InvalidMnemonic {
+        /// The position of the mnemonic in the input.
+        index: usize,
+        /// The cause of the error.
+        error: MnemonicError,
+    },
+    /// No mnemonics were provided.
+    NoMnemonics,
+    /// A mnemonic belongs to a different split, or to a different group setup, than the first one.
+    MismatchedMnemonics {
+        /// The position of the mnemonic in the input.
+        index: usize,
+    },
+    /// Two different mnemonics have the same group and member index.
+    DuplicateMemberIndex {
+        /// The group index.
+        group: u8,
+        /// The member index.
+        member: u8,
+    },
+    /// The number of groups differs from the group threshold.
+    WrongGroupCount {
+        /// The group threshold.
+        needed: usize,
+        /// The number of groups provided.
+        found: usize,
+    },
+    /// The number of mnemonics of a group differs from its member threshold.
+    WrongMemberCount {
+        /// The group index.
+        group: u8,
+        /// The member threshold of the group.
+        needed: usize,
+        /// The number of mnemonics provided for the group.
+        found: usize,
+    },
+    /// The digest of a recovered secret does not match, so the mnemonics were altered or mixed up.
+    InvalidDigest,
+}
+
+impl core::fmt::Display for Slip39Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Slip39Error::InvalidSecretLength { length } => {
+                write!(f, "master secret of {} bytes must be of an even length of at least {} bytes", length, MIN_SECRET_LEN)
+            }
+            Slip39Error::InvalidPassphrase => write!(f, "passphrase must only contain printable ASCII characters"),
+            Slip39Error::InvalidIterationExponent { exponent } => {
+                write!(f, "iteration exponent {} is outside of the range 0..=15", exponent)
+            }
+            Slip39Error::InvalidGroupThreshold { threshold, groups } => {
+                write!(f, "group threshold {} is invalid for {} groups", threshold, groups)
+            }
+            Slip39Error::InvalidMemberThreshold { group, threshold, members } => {
+                write!(f, "member threshold {} is invalid for {} members in group {}", threshold, members, group)
+            }
+            Slip39Error::InvalidMnemonic { index, error } => write!(f, "mnemonic {} is invalid: {}", index, error),
+            Slip39Error::NoMnemonics => write!(f, "no mnemonics provided"),
+            Slip39Error::MismatchedMnemonics { index } => {
+                write!(f, "mnemonic {} does not belong to the same split as the first mnemonic", index)
+            }
+            Slip39Error::DuplicateMemberIndex { group, member } => {
+                write!(f, "different mnemonics for member {} of group {}", member, group)
+            }
+            Slip39Error::WrongGroupCount { needed, found } => write!(f, "need {} groups, have {}", needed, found),
+            Slip39Error::WrongMemberCount { group, needed, found } => {
+                write!(f, "need {} mnemonics for group {}, have {}", needed, group, found)
+            }
+            Slip39Error::InvalidDigest => write!(f, "invalid digest of the recovered secret"),
+        }
+    }
+}
+
+impl core::error::Error for Slip39Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Slip39Error::InvalidMnemonic { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A share of a SLIP-39 mnemonic, with the parameters it carries.
+#[derive(PartialEq, Eq)]
+struct Slip39Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Slip39Share {
+    /// Returns `true` if both shares belong to the same split.
+    fn same_split(&self, other: &Slip39Share) -> bool {
+        self.identifier == other.identifier
+            && self.extendable == other.extendable
+            && self.iteration_exponent == other.iteration_exponent
+            && self.group_threshold == other.group_threshold
+            && self.group_count == other.group_count
+            && self.value.len() == other.value.len()
+    }
+
+    /// Encodes the share as a mnemonic.
+    fn to_mnemonic(&self) -> String {
+        let id_exp = (self.identifier as u32) << 5 | (self.extendable as u32) << 4 | self.iteration_exponent as u32;
+        let params = (self.group_index as u32) << 16
+            | (self.group_threshold as u32 - 1) << 12
+            | (self.group_count as u32 - 1) << 8
+            | (self.member_index as u32) << 4
+            | (self.member_threshold as u32 - 1);
+
+        let mut indices = Zeroizing::new(vec![
+            (id_exp >> RADIX_BITS) as u16,
+            (id_exp & 0x3FF) as u16,
+            (params >> RADIX_BITS) as u16,
+            (params & 0x3FF) as u16,
+        ]);
+        indices.extend(value_to_words(&self.value));
+        let checksum = rs1024_checksum(customization(self.extendable), &indices);
+        indices.extend(checksum);
+
+        let words: Vec<&str> = indices.iter().map(|&i| WORDLIST[i as usize]).collect();
+        words.join(" ")
+    }
+
+    /// Decodes a share from a mnemonic.
+    ///
+    /// ## Errors
+    /// * The [`MnemonicError`] describing why the mnemonic is invalid.
+    fn from_mnemonic(mnemonic: &str) -> Result<Slip39Share, MnemonicError> {
+        let indices = parse_words(mnemonic)?;
+        if indices.len() < MIN_MNEMONIC_WORDS {
+            return Err(MnemonicError::InvalidLength { words: indices.len() });
+        }
+
+        let id_exp = (indices[0] as u32) << RADIX_BITS | indices[1] as u32;
+        let extendable = (id_exp >> 4) & 1 == 1;
+        if !rs1024_verify(customization(extendable), &indices) {
+            return Err(MnemonicError::InvalidChecksum);
+        }
+
+        let params = (indices[2] as u32) << RADIX_BITS | indices[3] as u32;
+        let nibble = |shift: u32| ((params >> shift) & 0xF) as u8;
+
+        // The value is padded at the front to a multiple of 10 bits, and its length is a multiple of 16 bits.
+        let value_words = &indices[HEADER_WORDS..indices.len() - CHECKSUM_WORDS];
+        let padding = (value_words.len() * RADIX_BITS as usize) % 16;
+        if padding > 8 {
+            return Err(MnemonicError::InvalidLength { words: indices.len() });
+        }
+        let value = words_to_value(value_words, padding as u32).ok_or(MnemonicError::InvalidPadding)?;
+
+        Ok(Slip39Share {
+            identifier: (id_exp >> 5) as u16,
+            extendable,
+            iteration_exponent: (id_exp & 0xF) as u8,
+            group_index: nibble(16),
+            group_threshold: nibble(12) + 1,
+            group_count: nibble(8) + 1,
+            member_index: nibble(4),
+            member_threshold: nibble(0) + 1,
+            value,
+        })
+    }
+}
+
+/// Splits a master secret into SLIP-39 mnemonics, organized in groups.
+///
+/// The master secret is encrypted with the passphrase, split into one share per group,
+/// and each group share is split again among the members of the group. Recovering the
+/// master secret takes `group_threshold` groups, each with as many mnemonics as its
+/// member threshold. The mnemonics are extendable and use an iteration exponent of one,
+/// the defaults of the reference implementation.
+///
+/// ## Arguments
+/// * `master_secret` - The master secret, of an even length of at least 16 bytes.
+/// * `passphrase` - The passphrase, of printable ASCII characters, that encrypts the master secret.
+/// * `group_threshold` - Minimum number of groups required to recover the master secret.
+/// * `groups` - The member threshold and member count of each group.
+///
+/// ## Returns
+/// * The mnemonics of each group if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`Slip39Error::InvalidSecretLength`] if the master secret is too short or of odd length.
+/// * [`Slip39Error::InvalidPassphrase`] if the passphrase is not printable ASCII.
+/// * [`Slip39Error::InvalidGroupThreshold`] or [`Slip39Error::InvalidMemberThreshold`] if the groups are invalid.
+#[cfg(feature = "std")]
+pub fn slip39_split(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+) -> Result<Vec<Vec<String>>, Slip39Error> {
+    slip39_split_with_rng(master_secret, passphrase, group_threshold, groups, 1, true, &mut rand::thread_rng())
+}
+
+/// Splits a master secret into SLIP-39 mnemonics, drawing all randomness from `rng`.
+///
+/// ## Arguments
+/// * `master_secret` - The master secret, of an even length of at least 16 bytes.
+/// * `passphrase` - The passphrase, of printable ASCII characters, that encrypts the master secret.
+/// * `group_threshold` - Minimum number of groups required to recover the master secret.
+/// * `groups` - The member threshold and member count of each group.
+/// * `iteration_exponent` - The exponent of the `10000 * 2^e` PBKDF2 iterations, within `0..=15`.
+/// * `extendable` - Whether the identifier is left out of the encryption, so that further splits of
+///   the same master secret and passphrase can be made with the same identifier.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * The mnemonics of each group if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`Slip39Error::InvalidIterationExponent`] if `iteration_exponent` is greater than 15.
+/// * The same as [`slip39_split`].
+pub fn slip39_split_with_rng<R: RngCore + CryptoRng + ?Sized>(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+    iteration_exponent: u8,
+    extendable: bool,
+    rng: &mut R,
+) -> Result<Vec<Vec<String>>, Slip39Error> {
+    // Validate the input parameters.
+    if master_secret.len() < MIN_SECRET_LEN || master_secret.len() % 2 != 0 {
+        return Err(Slip39Error::InvalidSecretLength { length: master_secret.len() });
+    }
+    if !passphrase.iter().all(|&c| (32..=126).contains(&c)) {
+        return Err(Slip39Error::InvalidPassphrase);
+    }
+    if iteration_exponent > 0xF {
+        return Err(Slip39Error::InvalidIterationExponent { exponent: iteration_exponent });
+    }
+    if group_threshold == 0 || group_threshold > groups.len() || groups.len() > MAX_SHARE_COUNT {
+        return Err(Slip39Error::InvalidGroupThreshold { threshold: group_threshold, groups: groups.len() });
+    }
+    for (group, &(threshold, members)) in groups.iter().enumerate() {
+        // Several members with a threshold of one would all get the same share.
+        if threshold == 0 || threshold > members || members > MAX_SHARE_COUNT || (threshold == 1 && members > 1) {
+            return Err(Slip39Error::InvalidMemberThreshold { group, threshold, members });
+        }
+    }
+
+    let identifier = (rng.next_u32() & 0x7FFF) as u16;
+    let encrypted = encrypt(master_secret, passphrase, iteration_exponent, identifier, extendable);
+
+    let group_shares = split_secret(group_threshold, groups.len(), &encrypted, rng);
+    let mnemonics = groups
+        .iter()
+        .zip(group_shares.iter())
+        .enumerate()
+        .map(|(group_index, (&(member_threshold, members), group_share))| {
+            split_secret(member_threshold, members, group_share, rng)
+                .into_iter()
+                .enumerate()
+                .map(|(member_index, value)| {
+                    Slip39Share {
+                        identifier,
+                        extendable,
+                        iteration_exponent,
+                        group_index: group_index as u8,
+                        group_threshold: group_threshold as u8,
+                        group_count: groups.len() as u8,
+                        member_index: member_index as u8,
+                        member_threshold: member_threshold as u8,
+                        value,
+                    }
+                    .to_mnemonic()
+                })
+                .collect()
+        })
+        .collect();
+    Ok(mnemonics)
+}
+
+/// Recovers a master secret from SLIP-39 mnemonics.
+///
+/// Exactly as many groups as the group threshold must be given, each with exactly as many
+/// mnemonics as its member threshold, in any order. A wrong passphrase does not fail, but
+/// yields a different master secret.
+///
+/// ## Arguments
+/// * `mnemonics` - The mnemonics, in any case and with any whitespace between the words.
+/// * `passphrase` - The passphrase that encrypted the master secret.
+///
+/// ## Returns
+/// * The master secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`Slip39Error::NoMnemonics`] if `mnemonics` is empty.
+/// * [`Slip39Error::InvalidMnemonic`] if a mnemonic cannot be decoded.
+/// * [`Slip39Error::MismatchedMnemonics`] or [`Slip39Error::DuplicateMemberIndex`] if the mnemonics do not fit together.
+/// * [`Slip39Error::WrongGroupCount`] or [`Slip39Error::WrongMemberCount`] if the number of mnemonics does not match the thresholds.
+/// * [`Slip39Error::InvalidDigest`] if the digest of a recovered secret does not match.
+pub fn slip39_combine<S: AsRef<str>>(mnemonics: &[S], passphrase: &[u8]) -> Result<Vec<u8>, Slip39Error> {
+    let shares = mnemonics
+        .iter()
+        .enumerate()
+        .map(|(index, mnemonic)| {
+            Slip39Share::from_mnemonic(mnemonic.as_ref()).map_err(|error| Slip39Error::InvalidMnemonic { index, error })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let first = shares.first().ok_or(Slip39Error::NoMnemonics)?;
+
+    // Sort the shares into groups, skipping repeated mnemonics.
+    let mut groups: Vec<Vec<&Slip39Share>> = Vec::new();
+    for (index, share) in shares.iter().enumerate() {
+        if !share.same_split(first) || share.group_threshold > share.group_count {
+            return Err(Slip39Error::MismatchedMnemonics { index });
+        }
+        match groups.iter_mut().find(|g| g[0].group_index == share.group_index) {
+            None => groups.push(vec![share]),
+            Some(group) => {
+                if group[0].member_threshold != share.member_threshold {
+                    return Err(Slip39Error::MismatchedMnemonics { index });
+                }
+                match group.iter().find(|s| s.member_index == share.member_index) {
+                    Some(&other) if other == share => {}
+                    Some(_) => {
+                        return Err(Slip39Error::DuplicateMemberIndex {
+                            group: share.group_index,
+                            member: share.member_index,
+                        })
+                    }
+                    None => group.push(share),
+                }
+            }
+        }
+    }
+
+    let group_threshold = first.group_threshold as usize;
+    if groups.len() != group_threshold {
+        return Err(Slip39Error::WrongGroupCount { needed: group_threshold, found: groups.len() });
+    }
+
+    // Recover the share of each group from its members.
+    let mut group_shares = Vec::with_capacity(groups.len());
+    for group in &groups {
+        let member_threshold = group[0].member_threshold as usize;
+        if group.len() != member_threshold {
+            return Err(Slip39Error::WrongMemberCount {
+                group: group[0].group_index,
+                needed: member_threshold,
+                found: group.len(),
+            });
+        }
+        let members: Vec<(u8, &[u8])> = group.iter().map(|s| (s.member_index, &s.value[..])).collect();
+        group_shares.push((group[0].group_index, recover_secret(member_threshold, &members)?));
+    }
+
+    let group_shares: Vec<(u8, &[u8])> = group_shares.iter().map(|(x, value)| (*x, &value[..])).collect();
+    let encrypted = recover_secret(group_threshold, &group_shares)?;
+    Ok(decrypt(&encrypted, passphrase, first.iteration_exponent, first.identifier, first.extendable).to_vec())
+}
+
+/// Returns the customization string of the checksum.
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_EXTENDABLE
+    } else {
+        CUSTOMIZATION
+    }
+}
+
+/// Splits a secret into `count` shares with member indices `0..count`, along with a digest share.
+///
+/// With a threshold of one, every share is a copy of the secret. Otherwise the secret is placed
+/// at x-coordinate 255 and its digest at 254, next to `threshold - 2` random shares, and the
+/// remaining shares are interpolated from those.
+fn split_secret<R: RngCore + CryptoRng + ?Sized>(
+    threshold: usize,
+    count: usize,
+    secret: &[u8],
+    rng: &mut R,
+) -> Vec<Zeroizing<Vec<u8>>> {
+    if threshold == 1 {
+        return (0..count).map(|_| Zeroizing::new(secret.to_vec())).collect();
+    }
+
+    let random_count = threshold - 2;
+    let mut shares: Vec<Zeroizing<Vec<u8>>> = (0..random_count)
+        .map(|_| {
+            let mut value = Zeroizing::new(vec![0u8; secret.len()]);
+            rng.fill_bytes(&mut value);
+            value
+        })
+        .collect();
+
+    // The digest share holds a truncated HMAC of the secret, keyed by the random rest of the share.
+    let mut digest_share = Zeroizing::new(vec![0u8; secret.len()]);
+    rng.fill_bytes(&mut digest_share[DIGEST_LEN..]);
+    let digest = Zeroizing::new(digest(secret, &digest_share[DIGEST_LEN..]));
+    digest_share[..DIGEST_LEN].copy_from_slice(&digest[..DIGEST_LEN]);
+
+    let mut x_samples: Vec<u8> = (0..random_count as u8).collect();
+    x_samples.extend([DIGEST_INDEX, SECRET_INDEX]);
+
+    let mut y_samples: Vec<&[u8]> = shares.iter().map(|share| &share[..]).collect();
+    y_samples.extend([&digest_share[..], secret]);
+
+    let mut interpolated = vec![Zeroizing::new(vec![0u8; secret.len()]); count - random_count];
+    for (i, share) in interpolated.iter_mut().enumerate() {
+        Polynomial::interpolate_batch(&x_samples, &y_samples, (random_count + i) as u8, share);
+    }
+
+    shares.extend(interpolated);
+    shares
+}
+
+/// Recovers a secret from shares created by [`split_secret`] and verifies its digest.
+///
+/// ## Errors
+/// * [`Slip39Error::InvalidDigest`] if the digest does not match.
+fn recover_secret(threshold: usize, shares: &[(u8, &[u8])]) -> Result<Zeroizing<Vec<u8>>, Slip39Error> {
+    if threshold == 1 {
+        return Ok(Zeroizing::new(shares[0].1.to_vec()));
+    }
+
+    let x_samples: Vec<u8> = shares.iter().map(|&(x, _)| x).collect();
+    let y_samples: Vec<&[u8]> = shares.iter().map(|&(_, value)| value).collect();
+    let len = shares[0].1.len();
+    let mut secret = Zeroizing::new(vec![0u8; len]);
+    let mut digest_share = Zeroizing::new(vec![0u8; len]);
+
+    Polynomial::interpolate_batch(&x_samples, &y_samples, SECRET_INDEX, &mut secret);
+    Polynomial::interpolate_batch(&x_samples, &y_samples, DIGEST_INDEX, &mut digest_share);
+
+    let digest = Zeroizing::new(digest(&secret, &digest_share[DIGEST_LEN..]));
+    if !bool::from(digest[..DIGEST_LEN].ct_eq(&digest_share[..DIGEST_LEN])) {
+        return Err(Slip39Error::InvalidDigest);
+    }
+    Ok(secret)
+}
+
+/// Encrypts the master secret with a four-round Feistel network keyed by the passphrase.
+fn encrypt(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Zeroizing<Vec<u8>> {
+    feistel(master_secret, passphrase, iteration_exponent, identifier, extendable, 0..ROUND_COUNT)
+}
+
+/// Decrypts the encrypted master secret by running the Feistel network in reverse.
+fn decrypt(
+    encrypted: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Zeroizing<Vec<u8>> {
+    feistel(encrypted, passphrase, iteration_exponent, identifier, extendable, (0..ROUND_COUNT).rev())
+}
+
+/// Runs the Feistel network over the given rounds, with `PBKDF2-HMAC-SHA256` as the round function.
+///
+/// Each round derives `F(i, R) = PBKDF2(i || passphrase, salt || R)` with `2500 * 2^e` iterations,
+/// where the salt is `"shamir" || identifier` for non-extendable shares and empty otherwise.
+fn feistel(
+    input: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    rounds: impl Iterator<Item = u8>,
+) -> Zeroizing<Vec<u8>> {
+    let half = input.len() / 2;
+    let mut left = Zeroizing::new(input[..half].to_vec());
+    let mut right = Zeroizing::new(input[half..].to_vec());
+
+    let mut salt = Zeroizing::new(Vec::with_capacity(CUSTOMIZATION.len() + 2 + half));
+    if !extendable {
+        salt.extend_from_slice(CUSTOMIZATION);
+        salt.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let salt_prefix = salt.len();
+
+    let mut password = Zeroizing::new(Vec::with_capacity(1 + passphrase.len()));
+    password.push(0);
+    password.extend_from_slice(passphrase);
+
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+    let mut round = Zeroizing::new(vec![0u8; half]);
+    for i in rounds {
+        password[0] = i;
+        salt.truncate(salt_prefix);
+        salt.extend_from_slice(&right);
+        pbkdf2_hmac::<Sha256>(&password, &salt, iterations, &mut round);
+
+        for (l, r) in left.iter_mut().zip(round.iter()) {
+            *l ^= r;
+        }
+        core::mem::swap(&mut left, &mut right);
+    }
+
+    // The halves are swapped back after the last round.
+    right.extend_from_slice(&left);
+    right
+}
+
+/// Converts a value into words of 10 bits, padding it with zero bits at the front.
+fn value_to_words(value: &[u8]) -> Vec<u16> {
+    let word_count = (value.len() * 8).div_ceil(RADIX_BITS as usize);
+    let mut words = Vec::with_capacity(word_count);
+    // The padding bits are zero, so they only need to be counted.
+    let (mut buffer, mut bits) = (0u32, (word_count * RADIX_BITS as usize - value.len() * 8) as u32);
+
+    for &byte in value {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        if bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            words.push(((buffer >> bits) & 0x3FF) as u16);
+        }
+    }
+    words
+}
+
+/// Converts words of 10 bits into a value, dropping `padding` bits at the front.
+///
+/// ## Returns
+/// * The value, or `None` if any of the padding bits is not zero.
+fn words_to_value(words: &[u16], padding: u32) -> Option<Zeroizing<Vec<u8>>> {
+    let mut value = Zeroizing::new(Vec::with_capacity((words.len() * RADIX_BITS as usize - padding as usize) / 8));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+
+    for (i, &word) in words.iter().enumerate() {
+        buffer = (buffer << RADIX_BITS) | word as u32;
+        bits += RADIX_BITS;
+        if i == 0 {
+            // The first word holds all of the padding, since it is at most 8 bits.
+            if buffer >> (bits - padding) != 0 {
+                return None;
+            }
+            bits -= padding;
+        }
+        while bits >= 8 {
+            bits -= 8;
+            value.push((buffer >> bits) as u8);
+        }
+    }
+    Some(value)
+}
+
+// Test cases for SLIP-39.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // Decodes a hexadecimal string.
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("invalid hex")).collect()
+    }
+
+    // Returns the error that the invalid test vector with the given number must produce.
+    fn expected_error(number: u32) -> Slip39Error {
+        let invalid = |error| Slip39Error::InvalidMnemonic { index: 0, error };
+        match number {
+            2 | 21 => invalid(MnemonicError::InvalidChecksum),
+            3 => invalid(MnemonicError::InvalidPadding),
+            5 | 24 => Slip39Error::WrongMemberCount { group: 0, needed: 2, found: 1 },
+            6 | 7 | 9 | 12 => Slip39Error::MismatchedMnemonics { index: 1 },
+            10 => Slip39Error::MismatchedMnemonics { index: 0 },
+            11 => Slip39Error::DuplicateMemberIndex { group: 0, member: 2 },
+            13 => Slip39Error::InvalidDigest,
+            14 | 15 => Slip39Error::WrongGroupCount { needed: 2, found: 1 },
+            16 => Slip39Error::WrongMemberCount { group: 3, needed: 2, found: 1 },
+            40 => invalid(MnemonicError::InvalidLength { words: 19 }),
+            41 => invalid(MnemonicError::InvalidLength { words: 21 }),
+            _ => panic!("no expected error for invalid test vector {}", number),
+        }
+    }
+
+    // Test vectors in the format of the SLIP-39 reference implementation, all with the passphrase "TREZOR".
+    // An empty master secret marks mnemonics that must be rejected, with the error listed for their number.
+    #[test]
+    fn it_recovers_test_vectors() {
+        let vectors: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/vectors/slip39.json")).expect("invalid vectors");
+        let vectors = vectors.as_array().expect("vectors are not an array");
+        assert!(!vectors.is_empty());
+
+        for vector in vectors {
+            let description = vector[0].as_str().expect("missing description");
+            let mnemonics: Vec<&str> = vector[1]
+                .as_array()
+                .expect("missing mnemonics")
+                .iter()
+                .map(|m| m.as_str().expect("mnemonic is not a string"))
+                .collect();
+            let master_secret = vector[2].as_str().expect("missing master secret");
+
+            let result = slip39_combine(&mnemonics, b"TREZOR");
+            if master_secret.is_empty() {
+                let number = description.split('.').next().and_then(|n| n.parse().ok()).expect("missing number");
+                assert_eq!(result, Err(expected_error(number)), "{}", description);
+            } else {
+                assert_eq!(result, Ok(from_hex(master_secret)), "{}", description);
+            }
+        }
+    }
+
+    // Mnemonics round-trip through the word encoding.
+    #[test]
+    fn it_encodes_shares() {
+        let mnemonic = "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist \
+                        rescue view short owner flip making coding armed";
+        let share = Slip39Share::from_mnemonic(mnemonic).expect("decode failed");
+        assert_eq!((share.group_threshold, share.group_count, share.member_index, share.member_threshold), (1, 1, 2, 2));
+        assert_eq!(share.to_mnemonic(), mnemonic.split_whitespace().collect::<Vec<_>>().join(" "));
+    }
+
+    // Any qualifying set of groups and members recovers the master secret.
+    #[test]
+    fn it_splits_into_groups() {
+        let master_secret = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ012345";
+        let mut rng = ChaCha20Rng::from_seed([7; 32]);
+        let groups = slip39_split_with_rng(master_secret, b"pass", 2, &[(1, 1), (2, 3), (3, 5)], 0, false, &mut rng)
+            .expect("split failed");
+        assert_eq!(groups.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 3, 5]);
+
+        let mnemonics = [&groups[0][0], &groups[1][2], &groups[1][0]];
+        assert_eq!(slip39_combine(&mnemonics, b"pass").as_deref(), Ok(&master_secret[..]));
+
+        let mnemonics = [&groups[2][4], &groups[1][1], &groups[2][0], &groups[2][2], &groups[1][2]];
+        assert_eq!(slip39_combine(&mnemonics, b"pass").as_deref(), Ok(&master_secret[..]));
+
+        // A wrong passphrase yields a different master secret rather than an error.
+        let recovered = slip39_combine(&mnemonics, b"wrong").expect("combine failed");
+        assert_ne!(recovered, master_secret);
+
+        let groups = slip39_split_with_rng(&master_secret[..16], b"", 1, &[(2, 2)], 1, true, &mut rng)
+            .expect("split failed");
+        assert_eq!(slip39_combine(&groups[0], b"").as_deref(), Ok(&master_secret[..16]));
+    }
+
+    // Invalid split parameters are rejected.
+    #[test]
+    fn it_fails_to_split() {
+        let secret = [0u8; 16];
+        let mut rng = ChaCha20Rng::from_seed([8; 32]);
+        assert_eq!(
+            slip39_split_with_rng(&secret[..15], b"", 1, &[(1, 1)], 1, true, &mut rng),
+            Err(Slip39Error::InvalidSecretLength { length: 15 })
+        );
+        assert_eq!(
+            slip39_split_with_rng(&secret, b"\n", 1, &[(1, 1)], 1, true, &mut rng),
+            Err(Slip39Error::InvalidPassphrase)
+        );
+        assert_eq!(
+            slip39_split_with_rng(&secret, b"", 2, &[(1, 1)], 1, true, &mut rng),
+            Err(Slip39Error::InvalidGroupThreshold { threshold: 2, groups: 1 })
+        );
+        assert_eq!(
+            slip39_split_with_rng(&secret, b"", 1, &[(1, 1), (1, 2)], 1, true, &mut rng),
+            Err(Slip39Error::InvalidMemberThreshold { group: 1, threshold: 1, members: 2 })
+        );
+        assert_eq!(
+            slip39_split_with_rng(&secret, b"", 1, &[(2, 17)], 1, true, &mut rng),
+            Err(Slip39Error::InvalidMemberThreshold { group: 0, threshold: 2, members: 17 })
+        );
+    }
+
+    // Missing, mixed up or altered mnemonics are detected.
+    #[test]
+    fn it_fails_to_combine() {
+        let mut rng = ChaCha20Rng::from_seed([9; 32]);
+        let groups = slip39_split_with_rng(&[1; 16], b"", 2, &[(2, 3), (2, 3)], 1, true, &mut rng)
+            .expect("split failed");
+        let other = slip39_split_with_rng(&[1; 16], b"", 2, &[(2, 3), (2, 3)], 1, true, &mut rng)
+            .expect("split failed");
+
+        assert_eq!(slip39_combine::<&str>(&[], b""), Err(Slip39Error::NoMnemonics));
+        assert_eq!(
+            slip39_combine(&[&groups[0][0], &groups[0][1]], b""),
+            Err(Slip39Error::WrongGroupCount { needed: 2, found: 1 })
+        );
+        assert_eq!(
+            slip39_combine(&[&groups[0][0], &groups[0][1], &groups[1][0]], b""),
+            Err(Slip39Error::WrongMemberCount { group: 1, needed: 2, found: 1 })
+        );
+        // The two splits draw different identifiers from the generator.
+        assert_eq!(
+            slip39_combine(&[&groups[0][0], &other[0][1]], b""),
+            Err(Slip39Error::MismatchedMnemonics { index: 1 })
+        );
+
+        let mut typo: Vec<&str> = groups[0][0].split(' ').collect();
+        typo[5] = if typo[5] == "academic" { "acid" } else { "academic" };
+        assert!(matches!(
+            slip39_combine(&[typo.join(" ")], b""),
+            Err(Slip39Error::InvalidMnemonic { index: 0, error: MnemonicError::InvalidChecksum })
+        ));
+    }
+
+    // Shares of different splits with the same identifier fail the digest check.
+    #[test]
+    fn it_detects_invalid_digest() {
+        // The identifier is drawn first, so the same seed reuses it for a different secret.
+        let first = slip39_split_with_rng(&[1; 16], b"", 1, &[(2, 3)], 0, true, &mut ChaCha20Rng::from_seed([1; 32]))
+            .expect("split failed");
+        let second = slip39_split_with_rng(&[2; 16], b"", 1, &[(2, 3)], 0, true, &mut ChaCha20Rng::from_seed([1; 32]))
+            .expect("split failed");
+        assert_eq!(slip39_combine(&[&first[0][0], &second[0][1]], b""), Err(Slip39Error::InvalidDigest));
+    }
+}
@@ -1,9 +1,20 @@
 // #![forbid(unsafe_code)]
 // #![warn(clippy::all)]
 
-use std::marker::{PhantomData, PhantomPinned};
+use alloc::{string::String, vec, vec::Vec};
+use core::marker::{PhantomData, PhantomPinned};
 
+use rand::{CryptoRng, Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use zeroize::Zeroizing;
+
+use crate::ops::bitslice::LANES;
+use crate::ops::simd::{Backend, Multiplier};
 use crate::polynomial::Polynomial;
+use crate::share::Share;
+
+/// The number of secret bytes whose polynomials are generated and evaluated together.
+const BATCH_LEN: usize = 1024;
 
 /// A type that can be used as a secret.
 pub trait Secret {
@@ -21,7 +32,7 @@ pub trait Secret {
     }
 
     /// Returns an iterator over the bytes of the secret.
-    fn iter(&self) -> std::slice::Iter<u8>;
+    fn iter(&self) -> core::slice::Iter<'_, u8>;
 }
 
 // The existence of this function makes the compiler catch if the Secret
@@ -40,7 +51,7 @@ impl<const N: usize> Secret for &[u8; N] {
     }
 
     #[inline]
-    fn iter(&self) -> std::slice::Iter<u8> {
+    fn iter(&self) -> core::slice::Iter<'_, u8> {
         self[..].iter()
     }
 }
@@ -59,7 +70,7 @@ macro_rules! impl_usize {
             }
         
             #[inline]
-            fn iter(&self) -> std::slice::Iter<u8> {
+            fn iter(&self) -> core::slice::Iter<'_, u8> {
                 self[..].iter()
             }
         }
@@ -83,7 +94,7 @@ macro_rules! impl_vec {
             }
         
             #[inline]
-            fn iter(&self) -> std::slice::Iter<u8> {
+            fn iter(&self) -> core::slice::Iter<'_, u8> {
                 self.as_slice().iter()
             }
         }
@@ -106,7 +117,7 @@ macro_rules! string_impl {
             }
 
             #[inline]
-            fn iter(&self) -> std::slice::Iter<u8> {
+            fn iter(&self) -> core::slice::Iter<'_, u8> {
                 self.as_bytes().iter()
             }
         }
@@ -127,7 +138,7 @@ impl Secret for PhantomPinned {
     }
 
     #[inline]
-    fn iter(&self) -> std::slice::Iter<u8> {
+    fn iter(&self) -> core::slice::Iter<'_, u8> {
         [].iter()
     }
 }
@@ -144,11 +155,84 @@ impl<T: ?Sized> Secret for PhantomData<T> {
     }
 
     #[inline]
-    fn iter(&self) -> std::slice::Iter<u8> {
+    fn iter(&self) -> core::slice::Iter<'_, u8> {
         [].iter()
     }
 }
 
+/// An error returned when a secret cannot be split with the requested parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitError {
+    /// The threshold is outside of the supported range `2..=255`.
+    ThresholdOutOfRange {
+        /// The requested threshold.
+        threshold: usize,
+    },
+    /// More parts were requested than there are non-zero x-coordinates in `GF(2^8)`.
+    TooManyParts {
+        /// The requested number of parts.
+        parts: usize,
+    },
+    /// Fewer parts were requested than are needed to reconstruct the secret.
+    PartsBelowThreshold {
+        /// The requested number of parts.
+        parts: usize,
+        /// The requested threshold.
+        threshold: usize,
+    },
+    /// The secret has no bytes to split.
+    EmptySecret,
+    /// An x-coordinate is zero, which would reveal the secret in the corresponding share.
+    ZeroX {
+        /// The position of the x-coordinate in the input.
+        index: usize,
+    },
+    /// The same x-coordinate was requested for two shares.
+    DuplicateX {
+        /// The repeated x-coordinate.
+        x: u8,
+        /// The position of the first occurrence in the input.
+        first: usize,
+        /// The position of the second occurrence in the input.
+        second: usize,
+    },
+    /// A share buffer does not have room for exactly one y-value per byte of the secret and the x-coordinate.
+    ShareLength {
+        /// The position of the buffer in the input.
+        index: usize,
+        /// The required length of the buffer.
+        expected: usize,
+        /// The length of the buffer.
+        found: usize,
+    },
+}
+
+impl core::fmt::Display for SplitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SplitError::ThresholdOutOfRange { threshold } => {
+                write!(f, "threshold {} is outside of the range 2..=255", threshold)
+            }
+            SplitError::TooManyParts { parts } => {
+                write!(f, "cannot split into {} parts, at most 255 are supported", parts)
+            }
+            SplitError::PartsBelowThreshold { parts, threshold } => {
+                write!(f, "parts ({}) cannot be less than the threshold ({})", parts, threshold)
+            }
+            SplitError::EmptySecret => write!(f, "cannot split an empty secret"),
+            SplitError::ZeroX { index } => write!(f, "x-coordinate {} is zero", index),
+            SplitError::DuplicateX { x, first, second } => {
+                write!(f, "x-coordinates {} and {} are both {}", first, second, x)
+            }
+            SplitError::ShareLength { index, expected, found } => {
+                write!(f, "share buffer {} is {} bytes long, expected {}", index, found, expected)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SplitError {}
+
 /// Splits a secret into multiple shares.
 ///
 /// ## Arguments
@@ -160,19 +244,273 @@ impl<T: ?Sized> Secret for PhantomData<T> {
 /// * A vector of shares if successful; otherwise, an error.
 ///
 /// ## Errors
-/// * Returns an error if parameters are invalid (e.g., `parts` < `threshold`).
-pub fn split<T: Secret>(secret: T, parts: usize, threshold: usize) -> Result<Vec<Vec<u8>>, Box<dyn std::error::Error>> {
+/// * [`SplitError::ThresholdOutOfRange`] if `threshold` is not within `2..=255`.
+/// * [`SplitError::TooManyParts`] if `parts` is greater than `255`.
+/// * [`SplitError::PartsBelowThreshold`] if `parts` is less than `threshold`.
+/// * [`SplitError::EmptySecret`] if the secret is empty.
+#[cfg(feature = "std")]
+pub fn split<T: Secret>(secret: T, parts: usize, threshold: usize) -> Result<Vec<Share>, SplitError> {
+    split_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into multiple shares, deterministically derived from `seed`.
+///
+/// **Deterministic mode.** The seed is expanded with the ChaCha20 DRBG and fully determines
+/// the polynomial coefficients and the permutation of the x-coordinates, so the same inputs
+/// always produce the same shares. This is intended for known-answer test vectors, audits and
+/// comparisons against other implementations. The shares are only as secret as the seed: it must
+/// be 32 uniformly random bytes that are never reused or disclosed, otherwise anyone holding it
+/// can recompute every share.
+///
+/// The output is stable for a given version of this crate, but may change whenever the way
+/// randomness is drawn changes.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `seed` - The 32-byte seed of the ChaCha20 DRBG.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`].
+pub fn split_deterministic<T: Secret>(secret: T, parts: usize, threshold: usize, seed: &[u8; 32]) -> Result<Vec<Share>, SplitError> {
+    split_with_rng(secret, parts, threshold, &mut ChaCha20Rng::from_seed(*seed))
+}
+
+/// Splits a secret into multiple shares, drawing all randomness from `rng`.
+///
+/// Both the polynomial coefficients and the permutation of the x-coordinates
+/// are taken from `rng`, which allows the source of entropy to be pinned
+/// (e.g. an HSM-backed or OS-only generator) or used where `thread_rng` is unavailable.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`].
+pub fn split_with_rng<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+    secret: T,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, SplitError> {
+    check_parameters(parts, threshold, secret.is_empty())?;
+    let x_coordinates = random_x_coordinates(parts, rng);
+
+    evaluate_shares(secret, &x_coordinates, threshold, rng)
+}
+
+/// Validates the parameters of [`split`].
+///
+/// ## Errors
+/// * The same as [`split`].
+pub(crate) fn check_parameters(parts: usize, threshold: usize, empty: bool) -> Result<(), SplitError> {
     // Validate the input parameters.
-    if parts < threshold || parts > 255 || !(2..=255).contains(&threshold) || secret.is_empty() {
-        return Err("invalid input parameters".into());
+    if !(2..=255).contains(&threshold) {
+        return Err(SplitError::ThresholdOutOfRange { threshold });
+    }
+    if parts > 255 {
+        return Err(SplitError::TooManyParts { parts });
     }
+    if parts < threshold {
+        return Err(SplitError::PartsBelowThreshold { parts, threshold });
+    }
+    if empty {
+        return Err(SplitError::EmptySecret);
+    }
+    Ok(())
+}
 
+/// Validates that the x-coordinates are non-zero and unique.
+///
+/// ## Errors
+/// * [`SplitError::ZeroX`] if any of the x-coordinates is zero.
+/// * [`SplitError::DuplicateX`] if an x-coordinate is repeated.
+pub(crate) fn check_x_coordinates(xs: &[u8]) -> Result<(), SplitError> {
+    // Remember where each x-coordinate was first seen so a duplicate can be reported precisely.
+    let mut seen = [None; 256];
+    for (idx, &x) in xs.iter().enumerate() {
+        if x == 0 {
+            return Err(SplitError::ZeroX { index: idx });
+        }
+        if let Some(first) = seen[x as usize] {
+            return Err(SplitError::DuplicateX { x, first, second: idx });
+        }
+        seen[x as usize] = Some(idx);
+    }
+    Ok(())
+}
+
+/// Draws `parts` distinct non-zero x-coordinates from `rng`.
+///
+/// The caller must ensure that `parts` is at most `255`.
+pub(crate) fn random_x_coordinates<R: RngCore + CryptoRng + ?Sized>(parts: usize, rng: &mut R) -> Vec<u8> {
+    shuffled_x_coordinates(rng)[..parts].to_vec()
+}
+
+/// Draws a random permutation of the non-zero values in `GF(2^8)` from `rng`, without allocating.
+fn shuffled_x_coordinates<R: RngCore + CryptoRng + ?Sized>(rng: &mut R) -> [u8; 255] {
     // Generate a sequence of non-zero values in GF(2^8)
-    let mut x_coordinates: Vec<_> = (1..=255).collect();
+    let mut x_coordinates = [0u8; 255];
+    for (x, value) in x_coordinates.iter_mut().zip(1..=255) {
+        *x = value;
+    }
 
     // Shuffle to create a random permutation of the x-coordinates.
-    let mut rng = rand::thread_rng();
-    rand::seq::SliceRandom::shuffle(x_coordinates.as_mut_slice(), &mut rng);
+    rand::seq::SliceRandom::shuffle(&mut x_coordinates[..], rng);
+    x_coordinates
+}
+
+/// Splits a secret into shares evaluated at exactly the given x-coordinates.
+///
+/// Unlike [`split`], which hands out x-coordinates at random, the share at position `i`
+/// of the result is always evaluated at `xs[i]`. This keeps the index of each holder stable
+/// across re-splits (see [`HolderRegistry`](crate::HolderRegistry)).
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `xs` - The non-zero, unique x-coordinates of the shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Returns
+/// * A vector of shares, one per x-coordinate, if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`SplitError::ThresholdOutOfRange`] if `threshold` is not within `2..=255`.
+/// * [`SplitError::ZeroX`] if any of the x-coordinates is zero.
+/// * [`SplitError::DuplicateX`] if an x-coordinate is repeated.
+/// * [`SplitError::PartsBelowThreshold`] if fewer x-coordinates than `threshold` are given.
+/// * [`SplitError::EmptySecret`] if the secret is empty.
+#[cfg(feature = "std")]
+pub fn split_at<T: Secret>(secret: T, xs: &[u8], threshold: usize) -> Result<Vec<Share>, SplitError> {
+    split_at_with_rng(secret, xs, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into shares evaluated at exactly the given x-coordinates,
+/// drawing the polynomial coefficients from `rng`.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `xs` - The non-zero, unique x-coordinates of the shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Returns
+/// * A vector of shares, one per x-coordinate, if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split_at`].
+pub fn split_at_with_rng<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+    secret: T,
+    xs: &[u8],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, SplitError> {
+    // Validate the input parameters.
+    if !(2..=255).contains(&threshold) {
+        return Err(SplitError::ThresholdOutOfRange { threshold });
+    }
+
+    check_x_coordinates(xs)?;
+
+    // Unique non-zero values guarantee at most 255 parts.
+    if xs.len() < threshold {
+        return Err(SplitError::PartsBelowThreshold { parts: xs.len(), threshold });
+    }
+    if secret.is_empty() {
+        return Err(SplitError::EmptySecret);
+    }
+
+    evaluate_shares(secret, xs, threshold, rng)
+}
+
+/// Splits a secret into shares written to caller-provided buffers, without allocating.
+///
+/// Each buffer receives the y-values of one share followed by its x-coordinate, the same
+/// layout as [`Share::as_bytes`], so it must be exactly one byte longer than the secret.
+/// Given the same generator, the shares are identical to those of [`split_with_rng`].
+///
+/// The polynomial coefficients are generated and evaluated 64 bytes of the secret at a time
+/// in buffers on the stack, which are cleared before returning. These buffers are sized for
+/// the largest threshold whatever the threshold in use, taking about 16.5 KiB of stack, which
+/// callers on small stacks such as embedded targets or threads spawned with a custom stack
+/// size should account for.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `shares` - The buffers of the shares to create, one per share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Errors
+/// * [`SplitError::ShareLength`] if a buffer is not exactly one byte longer than the secret.
+/// * The same as [`split`], where the number of parts is the number of buffers.
+pub fn split_into<S: AsMut<[u8]>, R: RngCore + CryptoRng + ?Sized>(
+    secret: &[u8],
+    shares: &mut [S],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<(), SplitError> {
+    check_parameters(shares.len(), threshold, secret.is_empty())?;
+    for (index, share) in shares.iter_mut().enumerate() {
+        let found = share.as_mut().len();
+        if found != secret.len() + 1 {
+            return Err(SplitError::ShareLength { index, expected: secret.len() + 1, found });
+        }
+    }
+
+    let x_coordinates = shuffled_x_coordinates(rng);
+    for (share, &x) in shares.iter_mut().zip(&x_coordinates) {
+        share.as_mut()[secret.len()] = x;
+    }
+
+    // One row per power, holding that coefficient of the polynomial of every byte of the chunk.
+    let mut rows = Zeroizing::new([[0u8; LANES]; 255]);
+    let mut coefficients = Zeroizing::new([0u8; 255]);
+    let mut next = Zeroizing::new([0u8; LANES]);
+    let backend = Backend::detect();
+
+    for (chunk_idx, chunk) in secret.chunks(LANES).enumerate() {
+        let offset = chunk_idx * LANES;
+
+        // Draw the coefficients byte by byte, in the same order as `Polynomial::generate`.
+        for (i, &byte) in chunk.iter().enumerate() {
+            coefficients[0] = byte;
+            rng.fill(&mut coefficients[1..threshold]);
+            for (row, &coefficient) in rows.iter_mut().zip(&coefficients[..threshold]) {
+                row[i] = coefficient;
+            }
+        }
+
+        for (share, &x) in shares.iter_mut().zip(&x_coordinates) {
+            let y = &mut share.as_mut()[offset..offset + chunk.len()];
+            Polynomial::horner(&rows[..threshold], &Multiplier::new(backend, x), y, &mut next[..chunk.len()]);
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates a polynomial per byte of the secret and evaluates it at each x-coordinate.
+///
+/// The caller must ensure that the parameters have been validated, in particular that
+/// `x_coordinates` are non-zero and unique and that `threshold` is within `2..=255`.
+fn evaluate_shares<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+    secret: T,
+    x_coordinates: &[u8],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, SplitError> {
+    let parts = x_coordinates.len();
 
     // Set `share_size` to be equal to the length of the secret.
     let share_size = secret.len();
@@ -186,26 +524,42 @@ pub fn split<T: Secret>(secret: T, parts: usize, threshold: usize) -> Result<Vec
         shares[idx][share_size] = x_coordinates[idx];
     }
 
+    let mut ys: Vec<&mut [u8]> = shares.iter_mut().map(|share| &mut share[..share_size]).collect();
+    evaluate_into(secret.iter().as_slice(), x_coordinates, threshold, rng, &mut ys);
+
+    Ok(shares.into_iter().map(Share::from_raw).collect())
+}
+
+/// Generates a polynomial per byte of the secret and evaluates it at each x-coordinate,
+/// setting `ys[p][i]` to the value of the polynomial of byte `i` at `x_coordinates[p]`.
+///
+/// The caller must ensure that the parameters have been validated, in particular that
+/// `x_coordinates` are non-zero and unique and that `threshold` is within `2..=255`,
+/// and that each of `ys` holds at least `secret.len()` bytes.
+pub(crate) fn evaluate_into<R: RngCore + CryptoRng + ?Sized, S: AsMut<[u8]>>(
+    secret: &[u8],
+    x_coordinates: &[u8],
+    threshold: usize,
+    rng: &mut R,
+    ys: &mut [S],
+) {
     // For a polynomial of degree `k−1`, you need `k` distinct points to uniquely determine it,
     // therefor we generate a polynomial of degree `threshold - 1`.
     let degree = (threshold - 1) as u8;
 
-    // For each byte in the secret, create a polynomial and evaluate it at each x-coordinate.
-    for (s_idx, &secret_byte) in secret.iter().enumerate() {
-        // Generate a polynomial for the current byte of the secret.
-        let polynomial = Polynomial::generate(secret_byte, degree);
-
-        for p_idx in 0..parts {
-            // Access the x-coordinate for the current share.
-            let x = x_coordinates[p_idx];
-            // Evaluate the polynomial at the x-coordinate. This calculates
-            // the y-value of the polynomial, effectively generating a part
-            // of the share.
-            let y = polynomial.evaluate(x);
-            // Assign the evaluated y-value to the current share.
-            shares[p_idx][s_idx] = y;
-        }
-    }
+    let mut polynomials = Vec::with_capacity(BATCH_LEN);
+
+    // For each batch of the secret, create a polynomial per byte and evaluate them all at each x-coordinate.
+    for (batch_idx, batch) in secret.chunks(BATCH_LEN).enumerate() {
+        let offset = batch_idx * BATCH_LEN;
 
-    Ok(shares)
-}
\ No newline at end of file
+        // Generate a polynomial for each byte of the batch, in the order of the secret.
+        polynomials.clear();
+        polynomials.extend(batch.iter().map(|&b| Polynomial::generate(b, degree, rng)));
+
+        // Evaluate the polynomials at the x-coordinates. This calculates the y-values
+        // of the polynomials, effectively generating a part of each share.
+        let mut batch_ys: Vec<&mut [u8]> = ys.iter_mut().map(|y| &mut y.as_mut()[offset..offset + batch.len()]).collect();
+        Polynomial::evaluate_batch(&polynomials, x_coordinates, &mut batch_ys);
+    }
+}
@@ -0,0 +1,567 @@
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::split::SplitError;
+
+/// The maximum length of a secret, in bytes, matching the largest field of ssss.
+pub const SSSS_MAX_SECRET_LEN: usize = 128;
+
+/// The maximum length of a token, as accepted by ssss.
+const MAX_TOKEN_LEN: usize = 128;
+
+/// The number of XTEA rounds of the diffusion layer per byte of the secret.
+const DIFFUSION_ROUNDS: usize = 40;
+
+/// The low terms of the irreducible pentanomials `x^n + x^a + x^b + x^c + 1` of ssss,
+/// for every field size `n` from 8 to 1024 bits in steps of 8.
+const IRREDUCIBLE: [[usize; 3]; 128] = [
+    [4, 3, 1], [5, 3, 1], [4, 3, 1], [7, 3, 2], [5, 4, 3], [5, 3, 2], [7, 4, 2], [4, 3, 1],
+    [10, 9, 3], [9, 4, 2], [7, 6, 2], [10, 9, 6], [4, 3, 1], [5, 4, 3], [4, 3, 1], [7, 2, 1],
+    [5, 3, 2], [7, 4, 2], [6, 3, 2], [5, 3, 2], [15, 3, 2], [11, 3, 2], [9, 8, 7], [7, 2, 1],
+    [5, 3, 2], [9, 3, 1], [7, 3, 1], [9, 8, 3], [9, 4, 2], [8, 5, 3], [15, 14, 10], [10, 5, 2],
+    [9, 6, 2], [9, 3, 2], [9, 5, 2], [11, 10, 1], [7, 3, 2], [11, 2, 1], [9, 7, 4], [4, 3, 1],
+    [8, 3, 1], [7, 4, 1], [7, 2, 1], [13, 11, 6], [5, 3, 2], [7, 3, 2], [8, 7, 5], [12, 3, 2],
+    [13, 10, 6], [5, 3, 2], [5, 3, 2], [9, 5, 2], [9, 7, 2], [13, 4, 3], [4, 3, 1], [11, 6, 4],
+    [18, 9, 6], [19, 18, 13], [11, 3, 2], [15, 9, 6], [4, 3, 1], [16, 5, 2], [15, 14, 6], [8, 5, 2],
+    [15, 11, 2], [11, 6, 2], [7, 5, 3], [8, 3, 1], [19, 16, 9], [11, 9, 6], [15, 7, 6], [13, 4, 3],
+    [14, 13, 3], [13, 6, 3], [9, 5, 2], [19, 13, 6], [19, 10, 3], [11, 6, 5], [9, 2, 1], [14, 3, 2],
+    [13, 3, 1], [7, 5, 4], [11, 9, 8], [11, 6, 5], [23, 16, 9], [19, 14, 6], [23, 10, 2], [8, 3, 2],
+    [5, 4, 3], [9, 6, 4], [4, 3, 2], [13, 8, 6], [13, 11, 1], [13, 10, 3], [11, 6, 5], [19, 17, 4],
+    [15, 14, 7], [13, 9, 6], [9, 7, 3], [9, 7, 1], [14, 3, 2], [11, 8, 2], [11, 6, 4], [13, 5, 2],
+    [11, 5, 1], [11, 4, 1], [19, 10, 3], [21, 10, 6], [13, 3, 1], [15, 7, 5], [19, 18, 10], [7, 5, 3],
+    [12, 7, 2], [7, 5, 1], [14, 9, 6], [10, 3, 2], [15, 13, 12], [12, 11, 9], [16, 9, 7], [12, 9, 3],
+    [9, 5, 2], [17, 10, 6], [24, 9, 3], [17, 15, 13], [5, 4, 3], [19, 17, 8], [15, 6, 3], [19, 6, 1],
+];
+
+/// An error returned when a secret cannot be split into, or combined from, ssss shares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SsssError {
+    /// The threshold or the number of parts is invalid, or the secret is empty.
+    InvalidParameters(SplitError),
+    /// The secret is longer than the largest field of ssss.
+    SecretTooLong {
+        /// The length of the secret.
+        length: usize,
+    },
+    /// The token is longer than 128 characters, or contains a dash or whitespace.
+    InvalidToken,
+    /// A share is not of the form `[token-]index-hex`, or its length is not a valid field size.
+    InvalidShare {
+        /// The position of the share in the input.
+        index: usize,
+    },
+    /// A share has a different security level, i.e. length, than the first share.
+    LengthMismatch {
+        /// The position of the share in the input.
+        index: usize,
+    },
+    /// Two shares have the same index.
+    DuplicateIndex {
+        /// The repeated share index.
+        share: u32,
+    },
+    /// Fewer shares were provided than the threshold.
+    TooFewShares {
+        /// The threshold.
+        needed: usize,
+        /// The number of shares provided.
+        found: usize,
+    },
+}
+
+impl core::fmt::Display for SsssError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SsssError::InvalidParameters(error) => write!(f, "{}", error),
+            SsssError::SecretTooLong { length } => {
+                write!(f, "secret of {} bytes exceeds the maximum of {} bytes", length, SSSS_MAX_SECRET_LEN)
+            }
+            SsssError::InvalidToken => write!(f, "token must be at most 128 characters without dashes or whitespace"),
+            SsssError::InvalidShare { index } => write!(f, "share {} is invalid", index),
+            SsssError::LengthMismatch { index } => write!(f, "share {} has a different security level", index),
+            SsssError::DuplicateIndex { share } => write!(f, "share index {} is repeated", share),
+            SsssError::TooFewShares { needed, found } => write!(f, "need at least {} shares, have {}", needed, found),
+        }
+    }
+}
+
+impl core::error::Error for SsssError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            SsssError::InvalidParameters(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<SplitError> for SsssError {
+    fn from(error: SplitError) -> Self {
+        SsssError::InvalidParameters(error)
+    }
+}
+
+/// An element of `GF(2^n)`, as little-endian 64-bit limbs with room for one bit above the field size.
+type Element = Zeroizing<Vec<u64>>;
+
+/// The field `GF(2^n)` of ssss, for `n` a multiple of 8 up to 1024.
+struct Field {
+    /// The field size `n`, in bits.
+    degree: usize,
+    /// The irreducible polynomial, including the `x^n` term.
+    modulus: Vec<u64>,
+}
+
+impl Field {
+    /// Creates the field of `degree` bits, which must be a multiple of 8 within `8..=1024`.
+    fn new(degree: usize) -> Field {
+        let mut modulus = vec![0u64; degree / 64 + 1];
+        for bit in [degree, 0].into_iter().chain(IRREDUCIBLE[degree / 8 - 1]) {
+            modulus[bit / 64] |= 1 << (bit % 64);
+        }
+        Field { degree, modulus }
+    }
+
+    /// Returns the zero element.
+    fn zero(&self) -> Element {
+        Zeroizing::new(vec![0u64; self.modulus.len()])
+    }
+
+    /// Converts an integer into an element.
+    fn element(&self, value: u32) -> Element {
+        let mut element = self.zero();
+        element[0] = value as u64;
+        element
+    }
+
+    /// Converts big-endian bytes, at most `n / 8` of them, into an element.
+    fn import(&self, bytes: &[u8]) -> Element {
+        let mut element = self.zero();
+        for (k, &byte) in bytes.iter().rev().enumerate() {
+            element[k / 8] |= (byte as u64) << (8 * (k % 8));
+        }
+        element
+    }
+
+    /// Converts an element into `n / 8` big-endian bytes.
+    fn export(&self, element: &[u64]) -> Zeroizing<Vec<u8>> {
+        let len = self.degree / 8;
+        let mut bytes = Zeroizing::new(vec![0u8; len]);
+        for k in 0..len {
+            bytes[len - 1 - k] = (element[k / 8] >> (8 * (k % 8))) as u8;
+        }
+        bytes
+    }
+
+    /// Adds two elements, which is a bitwise XOR.
+    fn add(&self, a: &[u64], b: &[u64]) -> Element {
+        Zeroizing::new(a.iter().zip(b).map(|(a, b)| a ^ b).collect())
+    }
+
+    /// Multiplies two elements by shifting and adding, reducing by the irreducible polynomial.
+    ///
+    /// The branches only depend on the field size, not on the elements.
+    fn mult(&self, a: &[u64], b: &[u64]) -> Element {
+        let mut shifted = Zeroizing::new(a.to_vec());
+        let mut product = self.zero();
+
+        for i in 0..self.degree {
+            if i > 0 {
+                // Multiply by `x`, then subtract the modulus if the `x^n` term is set.
+                for j in (1..shifted.len()).rev() {
+                    shifted[j] = (shifted[j] << 1) | (shifted[j - 1] >> 63);
+                }
+                shifted[0] <<= 1;
+                let carry = 0u64.wrapping_sub((shifted[self.degree / 64] >> (self.degree % 64)) & 1);
+                for (s, m) in shifted.iter_mut().zip(&self.modulus) {
+                    *s ^= m & carry;
+                }
+            }
+            let bit = 0u64.wrapping_sub((b[i / 64] >> (i % 64)) & 1);
+            for (p, s) in product.iter_mut().zip(shifted.iter()) {
+                *p ^= s & bit;
+            }
+        }
+        product
+    }
+
+    /// Computes the multiplicative inverse as `a^(2^n - 2)`.
+    fn inverse(&self, a: &[u64]) -> Element {
+        // Raise to `2^k - 1` for increasing `k`, up to `2^(n - 1) - 1`, then square once more.
+        let mut result = Zeroizing::new(a.to_vec());
+        for _ in 1..self.degree - 1 {
+            result = self.mult(&self.mult(&result, &result), a);
+        }
+        self.mult(&result, &result)
+    }
+}
+
+/// Splits a secret into shares in the text format of the `ssss-split` tool.
+///
+/// The secret is read as a big-endian number in `GF(2^n)`, where `n` is 8 bits per byte
+/// of the secret, so secrets can be padded with leading zero bytes for a higher security
+/// level, as with `ssss-split -s`. Shares are printed as `[token-]index-hex`, with the index
+/// zero-padded to the width of `parts` and `n / 4` hexadecimal digits.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split, of at most 128 bytes.
+/// * `parts` - Total number of shares to create.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `token` - An optional token prefixed to every share, as with `ssss-split -w`.
+/// * `diffusion` - Whether to apply the XTEA diffusion layer of ssss to secrets of at least 8 bytes;
+///   `false` matches `ssss-split -D`.
+///
+/// ## Returns
+/// * The shares, one line each, if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`SsssError::InvalidParameters`] with the same errors as [`split`](crate::split).
+/// * [`SsssError::SecretTooLong`] if the secret is longer than 128 bytes.
+/// * [`SsssError::InvalidToken`] if the token is longer than 128 characters or contains a dash or whitespace.
+#[cfg(feature = "std")]
+pub fn ssss_split(
+    secret: &[u8],
+    parts: usize,
+    threshold: usize,
+    token: Option<&str>,
+    diffusion: bool,
+) -> Result<Vec<String>, SsssError> {
+    ssss_split_with_rng(secret, parts, threshold, token, diffusion, &mut rand::thread_rng())
+}
+
+/// Splits a secret into shares in the text format of the `ssss-split` tool, drawing all randomness from `rng`.
+///
+/// ## Errors
+/// * The same as [`ssss_split`].
+pub fn ssss_split_with_rng<R: RngCore + CryptoRng + ?Sized>(
+    secret: &[u8],
+    parts: usize,
+    threshold: usize,
+    token: Option<&str>,
+    diffusion: bool,
+    rng: &mut R,
+) -> Result<Vec<String>, SsssError> {
+    // Validate the input parameters.
+    if !(2..=255).contains(&threshold) {
+        return Err(SplitError::ThresholdOutOfRange { threshold }.into());
+    }
+    if parts > 255 {
+        return Err(SplitError::TooManyParts { parts }.into());
+    }
+    if parts < threshold {
+        return Err(SplitError::PartsBelowThreshold { parts, threshold }.into());
+    }
+    if secret.is_empty() {
+        return Err(SplitError::EmptySecret.into());
+    }
+    if secret.len() > SSSS_MAX_SECRET_LEN {
+        return Err(SsssError::SecretTooLong { length: secret.len() });
+    }
+    if let Some(token) = token {
+        if token.len() > MAX_TOKEN_LEN || token.contains(|c: char| c == '-' || c.is_whitespace()) {
+            return Err(SsssError::InvalidToken);
+        }
+    }
+
+    let field = Field::new(secret.len() * 8);
+    let mut intercept = Zeroizing::new(secret.to_vec());
+    if diffusion && secret.len() >= 8 {
+        diffuse(&mut intercept, false);
+    }
+
+    let mut coefficients = vec![field.import(&intercept)];
+    let mut random = Zeroizing::new(vec![0u8; secret.len()]);
+    for _ in 1..threshold {
+        rng.fill_bytes(&mut random);
+        coefficients.push(field.import(&random));
+    }
+
+    let width = parts.to_string().len();
+    let shares = (1..=parts as u32)
+        .map(|index| {
+            let y = horner(&field, &coefficients, &field.element(index));
+            let hex: String = field.export(&y).iter().map(|b| format!("{:02x}", b)).collect();
+            match token {
+                Some(token) => format!("{}-{:0width$}-{}", token, index, hex, width = width),
+                None => format!("{:0width$}-{}", index, hex, width = width),
+            }
+        })
+        .collect();
+    Ok(shares)
+}
+
+/// Combines shares created by the `ssss-split` tool, or by [`ssss_split`], to reconstruct the secret.
+///
+/// As with `ssss-combine`, the threshold must be given, since the shared polynomial has a
+/// leading coefficient of one above the coefficients derived from the secret. Only the
+/// first `threshold` shares are used. Tokens are ignored.
+///
+/// ## Arguments
+/// * `shares` - The shares, one per string, in the `[token-]index-hex` format.
+/// * `threshold` - The threshold the secret was split with.
+/// * `diffusion` - Whether the diffusion layer was applied; `false` matches `ssss-combine -D`.
+///
+/// ## Returns
+/// * The secret, as `n / 8` big-endian bytes, if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`SsssError::InvalidParameters`] if `threshold` is not within `2..=255`.
+/// * [`SsssError::TooFewShares`] if fewer than `threshold` shares are provided.
+/// * [`SsssError::InvalidShare`] or [`SsssError::LengthMismatch`] if a share is malformed.
+/// * [`SsssError::DuplicateIndex`] if two shares have the same index.
+pub fn ssss_combine<S: AsRef<str>>(shares: &[S], threshold: usize, diffusion: bool) -> Result<Vec<u8>, SsssError> {
+    if !(2..=255).contains(&threshold) {
+        return Err(SplitError::ThresholdOutOfRange { threshold }.into());
+    }
+    if shares.len() < threshold {
+        return Err(SsssError::TooFewShares { needed: threshold, found: shares.len() });
+    }
+
+    let mut points: Vec<(u32, Zeroizing<Vec<u8>>)> = Vec::with_capacity(threshold);
+    for (index, share) in shares[..threshold].iter().enumerate() {
+        let (x, y) = parse_share(share.as_ref()).ok_or(SsssError::InvalidShare { index })?;
+        if let Some((_, first)) = points.first() {
+            if first.len() != y.len() {
+                return Err(SsssError::LengthMismatch { index });
+            }
+        }
+        if points.iter().any(|&(other, _)| other == x) {
+            return Err(SsssError::DuplicateIndex { share: x });
+        }
+        points.push((x, y));
+    }
+
+    let field = Field::new(points[0].1.len() * 8);
+    // The index must fit the field.
+    if let Some(index) = points.iter().position(|&(x, _)| field.degree < 32 && x >> field.degree != 0) {
+        return Err(SsssError::InvalidShare { index });
+    }
+
+    // The polynomial is `x^t + c_{t-1} x^{t-1} + ... + c_0`, so `q(x) = p(x) - x^t` has degree
+    // below `t`, and its intercept `c_0` is found by Lagrange interpolation at zero.
+    let xs: Vec<Element> = points.iter().map(|&(x, _)| field.element(x)).collect();
+    let mut intercept = field.zero();
+    for (i, (x, y)) in xs.iter().zip(&points).map(|(x, (_, y))| (x, y)).enumerate() {
+        let mut power = field.element(1);
+        for _ in 0..threshold {
+            power = field.mult(&power, x);
+        }
+        let q = field.add(&field.import(y), &power);
+
+        let mut numerator = field.element(1);
+        let mut denominator = field.element(1);
+        for (j, other) in xs.iter().enumerate() {
+            if i != j {
+                numerator = field.mult(&numerator, other);
+                denominator = field.mult(&denominator, &field.add(x, other));
+            }
+        }
+        let basis = field.mult(&numerator, &field.inverse(&denominator));
+        intercept = field.add(&intercept, &field.mult(&q, &basis));
+    }
+
+    let mut secret = field.export(&intercept);
+    if diffusion && secret.len() >= 8 {
+        diffuse(&mut secret, true);
+    }
+    Ok(secret.to_vec())
+}
+
+/// Parses a share of the form `[token-]index-hex`.
+///
+/// ## Returns
+/// * The index and the big-endian value of the share, or `None` if the share is malformed.
+fn parse_share(share: &str) -> Option<(u32, Zeroizing<Vec<u8>>)> {
+    let fields: Vec<&str> = share.trim().split('-').collect();
+    let (index, hex) = match fields[..] {
+        [index, hex] | [_, index, hex] => (index, hex),
+        _ => return None,
+    };
+
+    let index: u32 = index.parse().ok().filter(|&x| x != 0)?;
+    if hex.is_empty() || hex.len() % 2 != 0 || hex.len() > SSSS_MAX_SECRET_LEN * 2 || !hex.is_ascii() {
+        return None;
+    }
+    let value = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    Some((index, Zeroizing::new(value)))
+}
+
+/// Evaluates `x^t + c_{t-1} x^{t-1} + ... + c_0` at `x` using Horner's method, as ssss does.
+fn horner(field: &Field, coefficients: &[Element], x: &[u64]) -> Element {
+    let mut y = Zeroizing::new(x.to_vec());
+    for coefficient in coefficients[1..].iter().rev() {
+        y = field.mult(&field.add(&y, coefficient), x);
+    }
+    field.add(&y, &coefficients[0])
+}
+
+/// Applies, or with `decode` reverts, the diffusion layer of ssss to big-endian bytes.
+///
+/// ssss runs XTEA with an all-zero key over overlapping 8-byte slices, 40 times per byte,
+/// on the number laid out as 16-bit big-endian words from the least significant.
+fn diffuse(bytes: &mut [u8], decode: bool) {
+    let len = bytes.len();
+    let mut v = Zeroizing::new(vec![0u8; len.div_ceil(2) * 2]);
+    for (k, &byte) in bytes.iter().rev().enumerate() {
+        v[k ^ 1] = byte;
+    }
+    // With an odd number of bytes, ssss moves the most significant byte out of its half-empty word.
+    if len % 2 == 1 {
+        v[len - 1] = v[len];
+    }
+
+    let slices = (0..DIFFUSION_ROUNDS * len).step_by(2);
+    if decode {
+        for i in slices.rev() {
+            xtea_slice(&mut v[..len], i, xtea_decipher);
+        }
+    } else {
+        for i in slices {
+            xtea_slice(&mut v[..len], i, xtea_encipher);
+        }
+    }
+
+    if len % 2 == 1 {
+        v[len] = v[len - 1];
+        v[len - 1] = 0;
+    }
+    for (k, byte) in bytes.iter_mut().rev().enumerate() {
+        *byte = v[k ^ 1];
+    }
+}
+
+/// Runs an XTEA block operation on the 8 bytes starting at `index`, wrapping around the end.
+fn xtea_slice(data: &mut [u8], index: usize, block: fn(&mut [u32; 2])) {
+    let len = data.len();
+    let mut v = [0u32; 2];
+    for (i, word) in v.iter_mut().enumerate() {
+        *word = (0..4).fold(0, |w, k| (w << 8) | data[(index + 4 * i + k) % len] as u32);
+    }
+    block(&mut v);
+    for (i, word) in v.iter().enumerate() {
+        for k in 0..4 {
+            data[(index + 4 * i + k) % len] = (word >> (24 - 8 * k)) as u8;
+        }
+    }
+}
+
+/// Enciphers a block with XTEA under the all-zero key.
+fn xtea_encipher(v: &mut [u32; 2]) {
+    let (mut sum, delta) = (0u32, 0x9E37_79B9u32);
+    for _ in 0..32 {
+        v[0] = v[0].wrapping_add((((v[1] << 4) ^ (v[1] >> 5)).wrapping_add(v[1])) ^ sum);
+        sum = sum.wrapping_add(delta);
+        v[1] = v[1].wrapping_add((((v[0] << 4) ^ (v[0] >> 5)).wrapping_add(v[0])) ^ sum);
+    }
+}
+
+/// Deciphers a block with XTEA under the all-zero key.
+fn xtea_decipher(v: &mut [u32; 2]) {
+    let (mut sum, delta) = (0xC6EF_3720u32, 0x9E37_79B9u32);
+    for _ in 0..32 {
+        v[1] = v[1].wrapping_sub((((v[0] << 4) ^ (v[0] >> 5)).wrapping_add(v[0])) ^ sum);
+        sum = sum.wrapping_sub(delta);
+        v[0] = v[0].wrapping_sub((((v[1] << 4) ^ (v[1] >> 5)).wrapping_add(v[1])) ^ sum);
+    }
+}
+
+// Test cases for the ssss compatibility.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // The example of the ssss manual page.
+    #[test]
+    fn it_combines_ssss_shares() {
+        let shares = [
+            "3-fa1c3a9c6df8af0779c36de6c33f6e36e989d0e0b91309",
+            "5-4756974923c0dce0a55f4774d09ca7a4865f64f56a4ee0",
+            "2-fbc74a03a50e14ab406c225afb5f45c40ae11976d2b665",
+        ];
+        assert_eq!(ssss_combine(&shares, 3, true), Ok(b"my secret root password".to_vec()));
+
+        let tokens = shares.map(|s| format!("root-{}", s));
+        assert_eq!(ssss_combine(&tokens, 3, true), Ok(b"my secret root password".to_vec()));
+    }
+
+    // The polynomials of the sizes that are powers of two are irreducible: for those, the smallest `k`
+    // with `x^(2^k) = x` modulo the polynomial is `n` exactly if the polynomial is irreducible.
+    #[test]
+    fn it_has_irreducible_polynomials() {
+        for degree in [8, 16, 32, 64, 128, 256, 512, 1024] {
+            let field = Field::new(degree);
+            let x = field.element(2);
+            let mut power = x.clone();
+            for k in 1..=degree {
+                power = field.mult(&power, &power);
+                assert_eq!(*power == *x, k == degree, "x^(2^{}) for n = {}", k, degree);
+            }
+        }
+    }
+
+    // The inverse multiplies to one.
+    #[test]
+    fn it_inverts() {
+        for degree in [8, 24, 184] {
+            let field = Field::new(degree);
+            let a = field.import(&[0xA5; 23][..degree / 8]);
+            assert_eq!(*field.mult(&a, &field.inverse(&a)), *field.element(1));
+        }
+    }
+
+    // The diffusion layer is reverted, for even and odd lengths.
+    #[test]
+    fn it_diffuses() {
+        for len in [8, 9, 23, 32] {
+            let original: Vec<u8> = (0..len as u8).collect();
+            let mut bytes = original.clone();
+            diffuse(&mut bytes, false);
+            assert_ne!(bytes, original);
+            diffuse(&mut bytes, true);
+            assert_eq!(bytes, original);
+        }
+    }
+
+    // Shares split here round-trip, with and without tokens and diffusion.
+    #[test]
+    fn it_splits_ssss_shares() {
+        let mut rng = ChaCha20Rng::from_seed([5; 32]);
+        let shares = ssss_split_with_rng(b"legacy backup", 12, 3, Some("vault"), true, &mut rng).expect("split failed");
+        assert_eq!(shares.len(), 12);
+        assert!(shares[0].starts_with("vault-01-") && shares[0].len() == "vault-01-".len() + 26);
+        assert_eq!(ssss_combine(&shares[9..], 3, true), Ok(b"legacy backup".to_vec()));
+
+        let shares = ssss_split_with_rng(b"short", 3, 2, None, false, &mut rng).expect("split failed");
+        assert!(shares[2].starts_with("3-"));
+        assert_eq!(ssss_combine(&[&shares[2], &shares[0]], 2, false), Ok(b"short".to_vec()));
+    }
+
+    // Invalid parameters and malformed shares are rejected.
+    #[test]
+    fn it_fails_with_invalid_input() {
+        let mut rng = ChaCha20Rng::from_seed([6; 32]);
+        assert_eq!(
+            ssss_split_with_rng(b"", 3, 2, None, true, &mut rng),
+            Err(SsssError::InvalidParameters(SplitError::EmptySecret))
+        );
+        assert_eq!(
+            ssss_split_with_rng(&[1; 129], 3, 2, None, true, &mut rng),
+            Err(SsssError::SecretTooLong { length: 129 })
+        );
+        assert_eq!(ssss_split_with_rng(b"secret", 3, 2, Some("a-b"), true, &mut rng), Err(SsssError::InvalidToken));
+
+        let shares = ["1-00ff", "2-00", "1-abcd", "x-abcd", "3-abcg", "4-0102"];
+        assert_eq!(ssss_combine(&shares[..1], 2, true), Err(SsssError::TooFewShares { needed: 2, found: 1 }));
+        assert_eq!(ssss_combine(&shares[..2], 2, true), Err(SsssError::LengthMismatch { index: 1 }));
+        assert_eq!(ssss_combine(&[shares[0], shares[2]], 2, true), Err(SsssError::DuplicateIndex { share: 1 }));
+        assert_eq!(ssss_combine(&[shares[0], shares[3]], 2, true), Err(SsssError::InvalidShare { index: 1 }));
+        assert_eq!(ssss_combine(&[shares[0], shares[4]], 2, true), Err(SsssError::InvalidShare { index: 1 }));
+        assert_eq!(ssss_combine(&["256-ab", "1-ab"], 2, true), Err(SsssError::InvalidShare { index: 0 }));
+    }
+}
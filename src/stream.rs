@@ -0,0 +1,517 @@
+use alloc::{vec, vec::Vec};
+use std::io::{self, Read, Write};
+
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::combine::CombineError;
+use crate::header::{ShareHeader, FLAG_STREAM, HEADER_LEN, SPLIT_ID_LEN};
+use crate::polynomial::Polynomial;
+use crate::reconstruct::Reconstructor;
+use crate::split::{check_parameters, evaluate_into, random_x_coordinates, SplitError};
+
+/// The maximum number of y-values in a chunk of a streamed share.
+pub(crate) const CHUNK_LEN: usize = 64 * 1024;
+
+/// An error returned when a secret cannot be split into or combined from streams.
+#[derive(Debug)]
+pub enum StreamError {
+    /// Reading the secret or writing a share failed.
+    Io(io::Error),
+    /// The parameters of the split are invalid.
+    Split(SplitError),
+    /// The headers of the shares cannot be combined.
+    Combine(CombineError),
+    /// A share was not written by [`split_stream`].
+    NotStreamed {
+        /// The position of the share in the input.
+        index: usize,
+    },
+    /// A share ended before its terminating chunk.
+    Truncated {
+        /// The position of the share in the input.
+        index: usize,
+    },
+    /// A chunk of a share differs in length from the same chunk of most shares.
+    Desynchronized {
+        /// The position of the share in the input.
+        index: usize,
+        /// The length of the chunk shared by most shares.
+        expected: usize,
+        /// The length of the chunk of the offending share.
+        found: usize,
+    },
+    /// A chunk is longer than any chunk written by [`split_stream`].
+    ChunkTooLong {
+        /// The position of the share in the input.
+        index: usize,
+        /// The length of the chunk.
+        length: usize,
+    },
+}
+
+impl core::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "{}", error),
+            StreamError::Split(error) => write!(f, "{}", error),
+            StreamError::Combine(error) => write!(f, "{}", error),
+            StreamError::NotStreamed { index } => write!(f, "share {} is not a streamed share", index),
+            StreamError::Truncated { index } => write!(f, "share {} ended early", index),
+            StreamError::Desynchronized { index, expected, found } => {
+                write!(f, "share {} has a chunk of {} bytes where {} were expected", index, found, expected)
+            }
+            StreamError::ChunkTooLong { index, length } => {
+                write!(f, "share {} has a chunk of {} bytes, at most {} are allowed", index, length, CHUNK_LEN)
+            }
+        }
+    }
+}
+
+impl core::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            StreamError::Io(error) => Some(error),
+            StreamError::Split(error) => Some(error),
+            StreamError::Combine(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<SplitError> for StreamError {
+    fn from(error: SplitError) -> Self {
+        StreamError::Split(error)
+    }
+}
+
+impl From<CombineError> for StreamError {
+    fn from(error: CombineError) -> Self {
+        StreamError::Combine(error)
+    }
+}
+
+/// Splits a secret read from `reader` into one share per writer, holding only one chunk in memory.
+///
+/// Each share starts with a [`ShareHeader`] marked as streamed, followed by the y-values in
+/// chunks of at most 64 KiB. Every chunk is prefixed with its length as a big-endian `u32`,
+/// and a chunk of length zero ends the share. All shares are chunked identically.
+///
+/// ## Arguments
+/// * `reader` - The source of the secret, read until its end.
+/// * `writers` - The sinks of the shares, one per share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+///
+/// ## Errors
+/// * [`StreamError::Split`] with the same errors as [`split`](crate::split),
+///   where the number of parts is the number of writers.
+/// * [`StreamError::Io`] if reading the secret or writing a share fails.
+pub fn split_stream<I: Read, W: Write>(reader: I, writers: &mut [W], threshold: usize) -> Result<(), StreamError> {
+    split_stream_with_rng(reader, writers, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret read from `reader` into one share per writer,
+/// drawing all randomness, including the split identifier, from `rng`.
+///
+/// ## Arguments
+/// * `reader` - The source of the secret, read until its end.
+/// * `writers` - The sinks of the shares, one per share.
+/// * `threshold` - Minimum number of shares required to reconstruct the secret.
+/// * `rng` - A cryptographically secure random number generator.
+///
+/// ## Errors
+/// * The same as [`split_stream`].
+pub fn split_stream_with_rng<I: Read, W: Write, R: RngCore + CryptoRng + ?Sized>(
+    mut reader: I,
+    writers: &mut [W],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<(), StreamError> {
+    // Read the first chunk before writing anything, so that an empty secret is refused.
+    let mut chunk = Zeroizing::new(vec![0u8; CHUNK_LEN]);
+    let mut len = read_chunk(&mut reader, &mut chunk)?;
+
+    let (x_coordinates, headers) = start_split(writers.len(), threshold, len == 0, rng)?;
+    for (writer, header) in writers.iter_mut().zip(&headers) {
+        writer.write_all(header)?;
+    }
+
+    let mut ys = vec![vec![0u8; CHUNK_LEN]; writers.len()];
+    while len > 0 {
+        evaluate_into(&chunk[..len], &x_coordinates, threshold, rng, &mut ys);
+
+        for (writer, y) in writers.iter_mut().zip(&ys) {
+            writer.write_all(&(len as u32).to_be_bytes())?;
+            writer.write_all(&y[..len])?;
+        }
+        len = read_chunk(&mut reader, &mut chunk)?;
+    }
+
+    // Terminate every share with an empty chunk.
+    for writer in writers.iter_mut() {
+        writer.write_all(&0u32.to_be_bytes())?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Combines shares written by [`split_stream`] to reconstruct the secret into `writer`, one chunk at a time.
+///
+/// The x-coordinates are taken from the headers, which must all describe the same split.
+/// Every share is read in step with the first one, and each header or chunk length is checked
+/// as soon as it is read, so a share that ends early or is invalid is reported as soon as it is
+/// reached. Chunk lengths are compared once the length of every share has been read. The chunks
+/// reconstructed before such an error have already been written to `writer`.
+///
+/// ## Arguments
+/// * `readers` - The sources of the shares, at least as many as the threshold.
+/// * `writer` - The sink of the secret.
+///
+/// ## Errors
+/// * [`StreamError::Combine`] with [`CombineError::InvalidHeader`] if a share does not start with a valid header,
+///   [`CombineError::MixedSplits`] if a share belongs to a different split than the first one,
+///   [`CombineError::TooFewShares`] if fewer shares than the threshold are provided,
+///   or [`CombineError::DuplicateX`] if two shares have the same x-coordinate.
+/// * [`StreamError::NotStreamed`] if a share was not written by [`split_stream`].
+/// * [`StreamError::Truncated`] if a share ends before its terminating chunk.
+/// * [`StreamError::Desynchronized`] if a chunk of a share differs in length from that of most shares.
+/// * [`StreamError::ChunkTooLong`] if a share has a chunk longer than [`split_stream`] writes.
+/// * [`StreamError::Io`] if reading a share or writing the secret fails.
+pub fn combine_stream<I: Read, W: Write>(readers: &mut [I], mut writer: W) -> Result<(), StreamError> {
+    if readers.len() < 2 {
+        return Err(CombineError::TooFewShares { needed: 2, found: readers.len() }.into());
+    }
+
+    // Validate each header as soon as it is read, so that a bad share is reported before reading on.
+    let mut xs = Vec::with_capacity(readers.len());
+    let mut first = None;
+    for (index, reader) in readers.iter_mut().enumerate() {
+        let mut bytes = [0u8; HEADER_LEN];
+        read_exact(reader, &mut bytes, index)?;
+        xs.push(check_header(&bytes, index, &mut first)?);
+    }
+    let reconstructor = finish_headers(&xs, first)?;
+
+    let mut ys = vec![vec![0u8; CHUNK_LEN]; readers.len()];
+    let mut secret = Zeroizing::new(vec![0u8; CHUNK_LEN]);
+    loop {
+        let mut prefixes = vec![[0u8; 4]; readers.len()];
+        for (index, (reader, prefix)) in readers.iter_mut().zip(prefixes.iter_mut()).enumerate() {
+            read_exact(reader, prefix, index)?;
+            prefix_len(prefix, index)?;
+        }
+        let len = chunk_len(&prefixes)?;
+        if len == 0 {
+            break;
+        }
+
+        for (index, (reader, y)) in readers.iter_mut().zip(ys.iter_mut()).enumerate() {
+            read_exact(reader, &mut y[..len], index)?;
+        }
+
+        let y_samples: Vec<&[u8]> = ys.iter().map(|y| &y[..len]).collect();
+        Polynomial::weighted_sum(reconstructor.weights(), &y_samples, &mut secret[..len]);
+        writer.write_all(&secret[..len])?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Validates the parameters of a streamed split and creates the header of each share.
+///
+/// ## Returns
+/// * The x-coordinate and the encoded header of each share.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+pub(crate) fn start_split<R: RngCore + CryptoRng + ?Sized>(
+    parts: usize,
+    threshold: usize,
+    empty: bool,
+    rng: &mut R,
+) -> Result<(Vec<u8>, Vec<[u8; HEADER_LEN]>), SplitError> {
+    check_parameters(parts, threshold, empty)?;
+    let x_coordinates = random_x_coordinates(parts, rng);
+
+    let mut split_id = [0u8; SPLIT_ID_LEN];
+    rng.fill_bytes(&mut split_id);
+
+    // The parameters have been validated, so they fit in a byte.
+    let headers = x_coordinates
+        .iter()
+        .map(|&x| ShareHeader::new(FLAG_STREAM, threshold as u8, parts as u8, split_id, x).to_bytes())
+        .collect();
+    Ok((x_coordinates, headers))
+}
+
+/// Ensures that the headers of streamed shares all describe the same split,
+/// and prepares the reconstruction at their x-coordinates.
+///
+/// ## Errors
+/// * The same as [`combine_stream`] for the headers.
+#[cfg(feature = "tokio")]
+pub(crate) fn start_combine(headers: &[[u8; HEADER_LEN]]) -> Result<Reconstructor, StreamError> {
+    let mut xs = Vec::with_capacity(headers.len());
+    let mut first = None;
+    for (index, bytes) in headers.iter().enumerate() {
+        xs.push(check_header(bytes, index, &mut first)?);
+    }
+    finish_headers(&xs, first)
+}
+
+/// Ensures that the header of the share at `index` is streamed and belongs to the same split as `first`,
+/// remembering it as `first` if it is the first header.
+///
+/// ## Returns
+/// * The x-coordinate of the share.
+fn check_header(bytes: &[u8; HEADER_LEN], index: usize, first: &mut Option<ShareHeader>) -> Result<u8, StreamError> {
+    let header = ShareHeader::parse(bytes).map_err(|error| CombineError::InvalidHeader { index, error })?;
+    if !header.is_streamed() {
+        return Err(StreamError::NotStreamed { index });
+    }
+    match first {
+        None => *first = Some(header),
+        Some(first) if !first.same_split(&header) => return Err(CombineError::MixedSplits { index }.into()),
+        Some(_) => {}
+    }
+    Ok(header.x())
+}
+
+/// Prepares the reconstruction at the x-coordinates of the checked headers.
+fn finish_headers(xs: &[u8], first: Option<ShareHeader>) -> Result<Reconstructor, StreamError> {
+    let threshold = first.map_or(2, |header| header.threshold());
+    if xs.len() < threshold {
+        return Err(CombineError::TooFewShares { needed: threshold, found: xs.len() }.into());
+    }
+    Ok(Reconstructor::new(xs)?)
+}
+
+/// Decodes the length of the next chunk of every share.
+///
+/// The length shared by most shares is taken as correct, the earliest one in a tie,
+/// so that a single corrupt share is reported whichever position it is given in.
+///
+/// ## Errors
+/// * [`StreamError::ChunkTooLong`] or [`StreamError::Desynchronized`], as for [`combine_stream`].
+pub(crate) fn chunk_len(prefixes: &[[u8; 4]]) -> Result<usize, StreamError> {
+    for (index, prefix) in prefixes.iter().enumerate() {
+        prefix_len(prefix, index)?;
+    }
+
+    let mut len = u32::from_be_bytes(prefixes[0]) as usize;
+    let mut votes = 0;
+    for prefix in prefixes {
+        let count = prefixes.iter().filter(|&other| other == prefix).count();
+        if count > votes {
+            len = u32::from_be_bytes(*prefix) as usize;
+            votes = count;
+        }
+    }
+
+    for (index, prefix) in prefixes.iter().enumerate() {
+        let found = u32::from_be_bytes(*prefix) as usize;
+        if found != len {
+            return Err(StreamError::Desynchronized { index, expected: len, found });
+        }
+    }
+    Ok(len)
+}
+
+/// Decodes the length of a chunk of the share at `index`.
+///
+/// ## Errors
+/// * [`StreamError::ChunkTooLong`] if the chunk is longer than [`split_stream`] writes.
+fn prefix_len(prefix: &[u8; 4], index: usize) -> Result<usize, StreamError> {
+    let length = u32::from_be_bytes(*prefix) as usize;
+    if length > CHUNK_LEN {
+        return Err(StreamError::ChunkTooLong { index, length });
+    }
+    Ok(length)
+}
+
+/// Fills `buf` from the share at `index`, reporting the end of the share as [`StreamError::Truncated`].
+fn read_exact<I: Read>(reader: &mut I, buf: &mut [u8], index: usize) -> Result<(), StreamError> {
+    reader.read_exact(buf).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => StreamError::Truncated { index },
+        _ => StreamError::Io(e),
+    })
+}
+
+/// Reads from `reader` until `buf` is full or the reader is exhausted.
+///
+/// ## Returns
+/// * The number of bytes read, less than `buf.len()` only at the end of the reader.
+fn read_chunk<I: Read>(reader: &mut I, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+// Test cases for the streaming split and combine.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use crate::combine;
+    use crate::share::Share;
+
+    // Splits a secret into streamed shares held in memory.
+    fn split_to_vecs(secret: &[u8], parts: usize, threshold: usize) -> Vec<Vec<u8>> {
+        let mut writers = vec![Vec::new(); parts];
+        split_stream(secret, &mut writers, threshold).expect("split failed");
+        writers
+    }
+
+    // Combines streamed shares held in memory.
+    fn combine_from_slices(shares: &[&[u8]]) -> Result<Vec<u8>, StreamError> {
+        let mut readers = shares.to_vec();
+        let mut secret = Vec::new();
+        combine_stream(&mut readers, &mut secret)?;
+        Ok(secret)
+    }
+
+    // Decodes the chunks of a streamed share into a share.
+    fn decode(bytes: &[u8]) -> Share {
+        let header = ShareHeader::parse(bytes).expect("invalid header");
+        assert!(header.is_streamed());
+
+        let mut ys = Vec::new();
+        let mut rest = &bytes[HEADER_LEN..];
+        loop {
+            let len = u32::from_be_bytes(rest[..4].try_into().expect("truncated share")) as usize;
+            if len == 0 {
+                break;
+            }
+            ys.extend_from_slice(&rest[4..4 + len]);
+            rest = &rest[4 + len..];
+        }
+        assert_eq!(rest.len(), 4);
+        Share::new(header.x(), &ys).expect("invalid share")
+    }
+
+    // A secret spanning several chunks is split into shares that combine to it.
+    #[test]
+    fn it_splits_stream() {
+        let secret: Vec<u8> = (0..2 * CHUNK_LEN + 3).map(|i| (i % 253) as u8).collect();
+        let mut writers = vec![Vec::new(); 4];
+        split_stream(&secret[..], &mut writers, 3).expect("split failed");
+
+        // The header, three chunks with their lengths, and the terminator.
+        assert_eq!(writers[0].len(), HEADER_LEN + secret.len() + 4 * 4);
+
+        let shares: Vec<Share> = writers.iter().map(|w| decode(w)).collect();
+        let first = ShareHeader::parse(&writers[0]).expect("invalid header");
+        let second = ShareHeader::parse(&writers[3]).expect("invalid header");
+        assert_eq!((first.threshold(), first.parts()), (3, 4));
+        assert_eq!(first.split_id(), second.split_id());
+        assert_eq!(combine(&shares[1..]).expect("combine failed"), secret);
+    }
+
+    // Streamed shares cannot be decoded as shares held in memory.
+    #[test]
+    fn it_marks_streamed_headers() {
+        let mut writers = vec![Vec::new(); 2];
+        split_stream(&b"test_secret"[..], &mut writers, 2).expect("split failed");
+
+        let flags = writers[0][6];
+        assert_eq!(flags, FLAG_STREAM);
+        assert_eq!(ShareHeader::decode(&writers[0]).map(|(h, _)| h), Err(crate::HeaderError::UnsupportedFlags { flags }));
+    }
+
+    // Invalid parameters and an empty secret are refused before anything is written.
+    #[test]
+    fn it_fails_to_split_stream() {
+        let mut writers = vec![Vec::new(); 2];
+        let error = split_stream(&b"test_secret"[..], &mut writers, 3).expect_err("split succeeded");
+        assert!(matches!(error, StreamError::Split(SplitError::PartsBelowThreshold { parts: 2, threshold: 3 })));
+
+        let error = split_stream(io::empty(), &mut writers, 2).expect_err("split succeeded");
+        assert!(matches!(error, StreamError::Split(SplitError::EmptySecret)));
+        assert!(writers.iter().all(Vec::is_empty));
+    }
+
+    // Streamed shares spanning several chunks are combined in any order.
+    #[test]
+    fn it_combines_stream() {
+        let secret: Vec<u8> = (0..CHUNK_LEN + 17).map(|i| (i % 241) as u8).collect();
+        let shares = split_to_vecs(&secret, 5, 3);
+
+        assert_eq!(combine_from_slices(&[&shares[4], &shares[0], &shares[2]]).expect("combine failed"), secret);
+        let all: Vec<&[u8]> = shares.iter().map(Vec::as_slice).collect();
+        assert_eq!(combine_from_slices(&all).expect("combine failed"), secret);
+    }
+
+    // Shares that end early or do not line up are named.
+    #[test]
+    fn it_fails_with_truncated_shares() {
+        let secret = vec![0x5A; CHUNK_LEN + 1];
+        let shares = split_to_vecs(&secret, 3, 2);
+
+        // Cut within the header, within a chunk and before the terminator.
+        for cut in [HEADER_LEN - 1, HEADER_LEN + 100, shares[1].len() - 2] {
+            let error = combine_from_slices(&[&shares[0], &shares[1][..cut]]).expect_err("combine succeeded");
+            assert!(matches!(error, StreamError::Truncated { index: 1 }), "{:?}", error);
+        }
+
+        // A share whose second chunk is missing is terminated too early.
+        let mut short = shares[2][..HEADER_LEN + 4 + CHUNK_LEN].to_vec();
+        short.extend_from_slice(&0u32.to_be_bytes());
+        let error = combine_from_slices(&[&shares[0], &short]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Desynchronized { index: 1, expected: 1, found: 0 }));
+        assert_eq!(error.to_string(), "share 1 has a chunk of 0 bytes where 1 were expected");
+
+        // The share that disagrees with the others is named, even when it comes first.
+        let error = combine_from_slices(&[&short, &shares[0], &shares[1]]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Desynchronized { index: 0, expected: 1, found: 0 }));
+    }
+
+    // Shares that do not form a streamed split are refused before anything is written.
+    #[test]
+    fn it_fails_to_combine_stream() {
+        let shares = split_to_vecs(b"test_secret", 3, 3);
+        let other = split_to_vecs(b"test_secret", 3, 3);
+
+        let error = combine_from_slices(&[&shares[0], &shares[1]]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Combine(CombineError::TooFewShares { needed: 3, found: 2 })));
+
+        let error = combine_from_slices(&[&shares[0], &shares[1], &other[2]]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Combine(CombineError::MixedSplits { index: 2 })));
+
+        let error = combine_from_slices(&[&shares[0], &shares[1], &shares[1]]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::Combine(CombineError::DuplicateX { first: 1, second: 2, .. })));
+
+        let in_memory = crate::split_with_header(b"test_secret", 3, 2).expect("split failed");
+        let error = combine_from_slices(&[&in_memory[0], &in_memory[1]]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::NotStreamed { index: 0 }));
+
+        let mut corrupt = shares[0].clone();
+        corrupt[HEADER_LEN..HEADER_LEN + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+        let error = combine_from_slices(&[&corrupt, &shares[1], &shares[2]]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::ChunkTooLong { index: 0, length: 0xFFFF_FFFF }));
+        let error = combine_from_slices(&[&shares[1], &shares[2], &corrupt]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::ChunkTooLong { index: 2, length: 0xFFFF_FFFF }));
+    }
+
+    // Each header is validated before the next share is read.
+    #[test]
+    fn it_validates_headers_as_read() {
+        let in_memory = crate::split_with_header(b"test_secret", 3, 2).expect("split failed");
+        let error = combine_from_slices(&[&in_memory[0], &[]]).expect_err("combine succeeded");
+        assert!(matches!(error, StreamError::NotStreamed { index: 0 }));
+    }
+}
@@ -0,0 +1,218 @@
+use alloc::{string::String, vec::Vec};
+
+use rand::{CryptoRng, RngCore};
+
+use crate::combine::{combine, CombineError};
+use crate::encoding::{DecodeError, Encoding};
+use crate::share::Share;
+use crate::split::{split_with_rng, Secret, SplitError};
+
+/// An error returned when unseal keys cannot be combined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VaultError {
+    /// An unseal key is neither hexadecimal nor base64, or does not form a share.
+    InvalidKey {
+        /// The position of the key in the input.
+        index: usize,
+        /// The cause of the error.
+        error: DecodeError,
+    },
+    /// The decoded shares cannot be combined.
+    Combine(CombineError),
+}
+
+impl core::fmt::Display for VaultError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VaultError::InvalidKey { index, error } => write!(f, "unseal key {} is invalid: {}", index, error),
+            VaultError::Combine(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl core::error::Error for VaultError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            VaultError::InvalidKey { error, .. } => Some(error),
+            VaultError::Combine(error) => Some(error),
+        }
+    }
+}
+
+impl From<CombineError> for VaultError {
+    fn from(error: CombineError) -> Self {
+        VaultError::Combine(error)
+    }
+}
+
+/// Splits a secret into unseal keys that HashiCorp Vault can combine.
+///
+/// Vault's `shamir` package uses the same field, the same byte layout of y-values followed
+/// by the x-coordinate and the same shuffled x-coordinates as [`split`](crate::split), so the
+/// shares only need to be encoded in standard base64, as printed by `vault operator init`.
+///
+/// ## Arguments
+/// * `secret` - The secret to be split.
+/// * `parts` - Total number of unseal keys to create.
+/// * `threshold` - Minimum number of unseal keys required to reconstruct the secret.
+///
+/// ## Returns
+/// * The unseal keys in base64 if successful; otherwise, an error.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+#[cfg(feature = "std")]
+pub fn split_vault_keys<T: Secret>(secret: T, parts: usize, threshold: usize) -> Result<Vec<String>, SplitError> {
+    split_vault_keys_with_rng(secret, parts, threshold, &mut rand::thread_rng())
+}
+
+/// Splits a secret into unseal keys that HashiCorp Vault can combine, drawing all randomness from `rng`.
+///
+/// ## Errors
+/// * The same as [`split`](crate::split).
+pub fn split_vault_keys_with_rng<T: Secret, R: RngCore + CryptoRng + ?Sized>(
+    secret: T,
+    parts: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<String>, SplitError> {
+    let shares = split_with_rng(secret, parts, threshold, rng)?;
+    Ok(shares.iter().map(|share| share.encode(Encoding::Base64)).collect())
+}
+
+/// Decodes an unseal key, as printed by `vault operator init`, into a share.
+///
+/// Like `vault operator unseal`, the key is read as hexadecimal if possible and as
+/// standard base64 otherwise. Surrounding whitespace is ignored.
+///
+/// ## Errors
+/// * The [`DecodeError`] of the base64 decoding if the key is neither hexadecimal nor base64.
+/// * [`DecodeError::InvalidShare`] if the decoded bytes do not form a share.
+pub fn decode_vault_key(key: &str) -> Result<Share, DecodeError> {
+    let key = key.trim();
+    let bytes = match Encoding::Hex.decode(key) {
+        Ok(bytes) => bytes,
+        Err(_) => Encoding::Base64.decode(key)?,
+    };
+    Ok(Share::try_from(bytes)?)
+}
+
+/// Combines unseal keys created by Vault, or by [`split_vault_keys`], to reconstruct the secret.
+///
+/// ## Arguments
+/// * `keys` - The unseal keys, each in hexadecimal or standard base64.
+///
+/// ## Returns
+/// * The original secret if successful; otherwise, an error.
+///
+/// ## Errors
+/// * [`VaultError::InvalidKey`] if a key cannot be decoded.
+/// * [`VaultError::Combine`] with the same errors as [`combine`](crate::combine).
+pub fn combine_vault_keys<S: AsRef<str>>(keys: &[S]) -> Result<Vec<u8>, VaultError> {
+    let shares = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| decode_vault_key(key.as_ref()).map_err(|error| VaultError::InvalidKey { index, error }))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(combine(&shares)?)
+}
+
+// Test cases for the Vault compatibility.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::borrow::ToOwned;
+    use crate::split_deterministic;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // Decodes a hexadecimal string.
+    fn from_hex(hex: &str) -> Vec<u8> {
+        Encoding::Hex.decode(hex).expect("invalid hex")
+    }
+
+    // A record of the Vault vectors.
+    #[derive(Default)]
+    struct Record {
+        direction: String,
+        seed: [u8; 32],
+        secret: Vec<u8>,
+        parts: usize,
+        threshold: usize,
+        keys: Vec<String>,
+        keys_hex: Vec<String>,
+    }
+
+    // Parses the records of `tests/vectors/vault.txt`.
+    fn records() -> Vec<Record> {
+        let vectors = include_str!("../tests/vectors/vault.txt");
+        vectors
+            .split("\n\n")
+            .filter(|r| r.starts_with("direction ="))
+            .map(|r| {
+                let mut record = Record::default();
+                for line in r.lines() {
+                    let (key, value) = line.split_once(" = ").expect("invalid line");
+                    match key {
+                        "direction" => record.direction = value.to_owned(),
+                        "seed" => record.seed.copy_from_slice(&from_hex(value)),
+                        "secret" => record.secret = from_hex(value),
+                        "parts" => record.parts = value.parse().expect("invalid parts"),
+                        "threshold" => record.threshold = value.parse().expect("invalid threshold"),
+                        "key" => record.keys.push(value.to_owned()),
+                        "key_hex" => record.keys_hex.push(value.to_owned()),
+                        _ => panic!("unknown key {}", key),
+                    }
+                }
+                record
+            })
+            .collect()
+    }
+
+    // Unseal keys produced by the Python port of Vault's shamir.go are combined, in base64 and in hexadecimal.
+    #[test]
+    fn it_combines_vault_keys() {
+        let records: Vec<_> = records().into_iter().filter(|r| r.direction == "vault").collect();
+        assert_eq!(records.len(), 2);
+
+        for record in records {
+            for keys in [&record.keys, &record.keys_hex] {
+                assert_eq!(combine_vault_keys(keys), Ok(record.secret.clone()));
+            }
+            assert_eq!(combine_vault_keys(&record.keys[1..]), Ok(record.secret.clone()));
+        }
+    }
+
+    // Shares split by this crate match the recorded keys, which the port of Vault's Combine reconstructs.
+    #[test]
+    fn it_splits_vault_keys() {
+        let records: Vec<_> = records().into_iter().filter(|r| r.direction == "crate").collect();
+        assert_eq!(records.len(), 2);
+
+        for record in records {
+            let shares = split_deterministic(&*record.secret, record.parts, record.threshold, &record.seed)
+                .expect("split failed");
+            let keys: Vec<String> = shares.iter().map(|s| s.encode(Encoding::Base64)).collect();
+            assert_eq!(keys, record.keys);
+            assert_eq!(combine_vault_keys(&keys[..record.threshold]), Ok(record.secret));
+        }
+
+        let keys = split_vault_keys_with_rng(b"unseal", 3, 2, &mut ChaCha20Rng::from_seed([1; 32]))
+            .expect("split failed");
+        assert_eq!(combine_vault_keys(&keys[1..]), Ok(b"unseal".to_vec()));
+    }
+
+    // Malformed unseal keys are reported with their position.
+    #[test]
+    fn it_fails_with_invalid_keys() {
+        let keys = ["  qWjf5xKPwA==\n", "S7TNzowaHg?="];
+        assert_eq!(
+            combine_vault_keys(&keys),
+            Err(VaultError::InvalidKey { index: 1, error: DecodeError::InvalidCharacter { position: 10, character: '?' } })
+        );
+        assert_eq!(
+            combine_vault_keys(&["qWjf5xKPwA=="]),
+            Err(VaultError::Combine(CombineError::TooFewShares { needed: 2, found: 1 }))
+        );
+    }
+}
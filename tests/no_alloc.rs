@@ -0,0 +1,61 @@
+// Test cases for the allocation-free 'split_into' and 'combine_into' functions.
+//
+// The counting allocator replaces the global allocator of the whole test binary,
+// so these tests live apart from the unit tests of the library.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use shamir::{combine_into, split_into, split_with_rng, Share};
+
+// Counts the allocations made by the current thread, so that tests running in parallel do not interfere.
+struct CountingAllocator;
+
+std::thread_local! {
+    static ALLOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { std::alloc::System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { std::alloc::System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Returns the number of allocations made by `f` on the current thread.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(std::cell::Cell::get);
+    f();
+    ALLOCATIONS.with(std::cell::Cell::get) - before
+}
+
+// The 'split_into' and 'combine_into' functions write into the given buffers without allocating.
+#[test]
+fn it_splits_and_combines_into_buffers() {
+    let secret: Vec<u8> = (0..200).map(|i| (i * 7) as u8).collect();
+    let mut buffers = vec![[0u8; 201]; 5];
+    let mut recovered = [0u8; 200];
+    let mut rng = ChaCha20Rng::from_seed([9; 32]);
+
+    let mut shares: Vec<&mut [u8]> = buffers.iter_mut().map(|b| &mut b[..]).collect();
+    let count = allocations(|| {
+        split_into(&secret, &mut shares, 3, &mut rng).expect("split failed");
+        let selected: [&[u8]; 3] = [&shares[4][..], &shares[0][..], &shares[2][..]];
+        combine_into(selected, &mut recovered).expect("combine failed");
+    });
+    assert_eq!(count, 0);
+    assert_eq!(recovered[..], secret[..]);
+
+    // The shares are the same as those of 'split_with_rng' with the same generator.
+    let expected = split_with_rng(&secret, 5, 3, &mut ChaCha20Rng::from_seed([9; 32])).expect("split failed");
+    let expected: Vec<Vec<u8>> = expected.iter().map(Share::to_bytes).collect();
+    assert_eq!(buffers.iter().map(|b| b.to_vec()).collect::<Vec<_>>(), expected);
+}
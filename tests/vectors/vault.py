@@ -0,0 +1,149 @@
+#!/usr/bin/env python3
+"""Generates tests/vectors/vault.txt.
+
+The field arithmetic, Split and Combine below are a line-by-line port of
+shamir/shamir.go from HashiCorp Vault, independent of this crate. Only the
+randomness of Split is replaced by a SHA-256 counter stream, so that the
+shares Vault would produce for those coefficients can be committed.
+"""
+import base64
+import hashlib
+import sys
+
+
+def add(a, b):
+    return a ^ b
+
+
+def mult(a, b):
+    r = 0
+    for i in reversed(range(8)):
+        r = ((-((b >> i) & 1) & a) ^ (-(r >> 7) & 0x1B) ^ (r + r)) & 0xFF
+    return r
+
+
+def inverse(a):
+    b = mult(a, a)
+    c = mult(a, b)
+    b = mult(c, c)
+    b = mult(b, b)
+    c = mult(b, c)
+    b = mult(b, b)
+    b = mult(b, b)
+    b = mult(b, c)
+    b = mult(b, b)
+    b = mult(a, b)
+    return mult(b, b)
+
+
+def div(a, b):
+    if b == 0:
+        raise ZeroDivisionError
+    return 0 if a == 0 else mult(a, inverse(b))
+
+
+def evaluate(coefficients, x):
+    if x == 0:
+        return coefficients[0]
+    out = coefficients[-1]
+    for coefficient in reversed(coefficients[:-1]):
+        out = add(mult(out, x), coefficient)
+    return out
+
+
+def interpolate(x_samples, y_samples, x):
+    result = 0
+    for i in range(len(x_samples)):
+        basis = 1
+        for j in range(len(x_samples)):
+            if i == j:
+                continue
+            num = add(x, x_samples[j])
+            denom = add(x_samples[i], x_samples[j])
+            basis = mult(basis, div(num, denom))
+        result = add(result, mult(y_samples[i], basis))
+    return result
+
+
+class Stream:
+    """Stands in for crypto/rand: SHA-256 of a label and a counter."""
+
+    def __init__(self, label):
+        self.label, self.counter, self.buffer = label, 0, b""
+
+    def read(self, n):
+        while len(self.buffer) < n:
+            block = self.label + self.counter.to_bytes(4, "big")
+            self.buffer += hashlib.sha256(block).digest()
+            self.counter += 1
+        out, self.buffer = self.buffer[:n], self.buffer[n:]
+        return out
+
+    def perm(self, n):
+        p = list(range(n))
+        for i in range(n - 1, 0, -1):
+            j = int.from_bytes(self.read(4), "big") % (i + 1)
+            p[i], p[j] = p[j], p[i]
+        return p
+
+
+def split(secret, parts, threshold, stream):
+    x_coordinates = stream.perm(255)
+    out = [bytearray(len(secret) + 1) for _ in range(parts)]
+    for idx in range(parts):
+        out[idx][len(secret)] = x_coordinates[idx] + 1
+    for idx, val in enumerate(secret):
+        coefficients = [val] + list(stream.read(threshold - 1))
+        for i in range(parts):
+            out[i][idx] = evaluate(coefficients, x_coordinates[i] + 1)
+    return [bytes(part) for part in out]
+
+
+def combine(parts):
+    assert len(parts) >= 2 and len(parts[0]) >= 2
+    assert all(len(part) == len(parts[0]) for part in parts)
+    x_samples = [part[-1] for part in parts]
+    assert len(set(x_samples)) == len(x_samples)
+    return bytes(interpolate(x_samples, [part[idx] for part in parts], 0) for idx in range(len(parts[0]) - 1))
+
+
+def vault_to_crate(label, secret, parts, threshold):
+    shares = split(secret, parts, threshold, Stream(label.encode()))
+    assert combine(shares[:threshold]) == secret
+    print("direction = vault")
+    print("secret = " + secret.hex())
+    for share in shares:
+        print("key = " + base64.b64encode(share).decode())
+    for share in shares:
+        print("key_hex = " + share.hex())
+    print()
+
+
+def crate_to_vault(seed, secret, parts, threshold, keys):
+    shares = [base64.b64decode(key) for key in keys]
+    for i in range(parts - threshold + 1):
+        assert combine(shares[i : i + threshold]) == secret
+    print("direction = crate")
+    print("seed = " + seed)
+    print("secret = " + secret.hex())
+    print("parts = %d" % parts)
+    print("threshold = %d" % threshold)
+    for key in keys:
+        print("key = " + key)
+    print()
+
+
+if __name__ == "__main__":
+    print("# Cross-implementation vectors for the Vault compatibility functions.")
+    print("#")
+    print("# Generated by tests/vectors/vault.py, a port of Vault's shamir/shamir.go.")
+    print("# Records with `direction = vault` hold unseal keys produced by the ported")
+    print("# Split; records with `direction = crate` hold keys produced by")
+    print("# `split_deterministic`, which the ported Combine reconstructs to `secret`.")
+    print()
+    vault_to_crate("root-key", hashlib.sha256(b"vault root key").digest(), 5, 3)
+    vault_to_crate("short", b"unseal", 3, 2)
+    # Lines of `seed secret parts threshold key...`, printed by the crate.
+    for line in filter(str.strip, sys.stdin):
+        seed, secret, parts, threshold, *keys = line.split()
+        crate_to_vault(seed, bytes.fromhex(secret), int(parts), int(threshold), keys)
@@ -0,0 +1,5 @@
+module github.com/bartick/shamir-secret-sharing/tests/vectors/vault
+
+go 1.21
+
+require github.com/hashicorp/vault v1.15.6
@@ -0,0 +1,118 @@
+// Command vault generates tests/vectors/vault.txt with the shamir package of HashiCorp Vault.
+//
+// Records with `direction = vault` hold unseal keys produced by shamir.Split. Records with
+// `direction = crate` are read from the current file on standard input; they hold keys produced
+// by `split_deterministic`, and every window of `threshold` consecutive keys must reconstruct
+// `secret` with shamir.Combine before the record is written back.
+//
+// Usage, from this directory:
+//
+//	go mod tidy
+//	go run . < ../vault.txt > ../vault.txt.new && mv ../vault.txt.new ../vault.txt
+package main
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/base64"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"log"
+	"os"
+	"strconv"
+	"strings"
+
+	"github.com/hashicorp/vault/shamir"
+)
+
+func main() {
+	input, err := io.ReadAll(os.Stdin)
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	fmt.Println("# Cross-implementation vectors for the Vault compatibility functions.")
+	fmt.Println("#")
+	fmt.Println("# Generated by tests/vectors/vault, which runs the shamir package of HashiCorp Vault.")
+	fmt.Println("# Records with `direction = vault` hold unseal keys produced by shamir.Split; records")
+	fmt.Println("# with `direction = crate` hold keys produced by `split_deterministic`, which")
+	fmt.Println("# shamir.Combine reconstructs to `secret`.")
+	fmt.Println()
+
+	rootKey := sha256.Sum256([]byte("vault root key"))
+	vaultToCrate(rootKey[:], 5, 3)
+	vaultToCrate([]byte("unseal"), 3, 2)
+
+	for _, block := range strings.Split(string(input), "\n\n") {
+		block = strings.TrimSpace(block)
+		if strings.HasPrefix(block, "direction = crate") {
+			crateToVault(block)
+		}
+	}
+}
+
+// vaultToCrate prints the unseal keys Vault splits the secret into.
+func vaultToCrate(secret []byte, parts, threshold int) {
+	shares, err := shamir.Split(secret, parts, threshold)
+	if err != nil {
+		log.Fatal(err)
+	}
+	combined, err := shamir.Combine(shares[:threshold])
+	if err != nil {
+		log.Fatal(err)
+	}
+	if !bytes.Equal(combined, secret) {
+		log.Fatal("shamir.Split does not round-trip")
+	}
+
+	fmt.Println("direction = vault")
+	fmt.Println("secret = " + hex.EncodeToString(secret))
+	for _, share := range shares {
+		fmt.Println("key = " + base64.StdEncoding.EncodeToString(share))
+	}
+	for _, share := range shares {
+		fmt.Println("key_hex = " + hex.EncodeToString(share))
+	}
+	fmt.Println()
+}
+
+// crateToVault checks that Vault combines the keys of a record produced by the crate, and prints it.
+func crateToVault(block string) {
+	var secret []byte
+	var threshold int
+	var keys [][]byte
+	for _, line := range strings.Split(block, "\n") {
+		key, value, ok := strings.Cut(line, " = ")
+		if !ok {
+			log.Fatalf("invalid line %q", line)
+		}
+		var err error
+		switch key {
+		case "secret":
+			secret, err = hex.DecodeString(value)
+		case "threshold":
+			threshold, err = strconv.Atoi(value)
+		case "key":
+			var share []byte
+			share, err = base64.StdEncoding.DecodeString(value)
+			keys = append(keys, share)
+		}
+		if err != nil {
+			log.Fatalf("invalid line %q: %v", line, err)
+		}
+	}
+
+	for i := 0; i+threshold <= len(keys); i++ {
+		combined, err := shamir.Combine(keys[i : i+threshold])
+		if err != nil {
+			log.Fatal(err)
+		}
+		if !bytes.Equal(combined, secret) {
+			log.Fatalf("keys %d to %d do not combine to the secret", i, i+threshold-1)
+		}
+	}
+
+	fmt.Println(block)
+	fmt.Println()
+}